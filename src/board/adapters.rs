@@ -0,0 +1,133 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+
+use super::{
+  char::CodeVisitor,
+  text::{parse_row, read_row, write_row},
+  BoardData, BoardError, CharacterCode, DynBoardData,
+};
+
+/// a [`serde_with`] adapter that serializes a [`CharacterCode`] by its [`CharacterCode::name`] instead of
+/// its number. both are accepted when deserializing. formats that are not human readable, such as bincode,
+/// always use the number.
+///
+/// # example
+/// ```
+/// #[serde_as]
+/// #[derive(Serialize, Deserialize)]
+/// struct Theme {
+///   #[serde_as(as = "CodeName")]
+///   accent: CharacterCode, // "red"
+///   #[serde_as(as = "Vec<CodeName>")]
+///   palette: Vec<CharacterCode>, // ["red", "orange", "yellow"]
+/// }
+/// ```
+pub struct CodeName;
+
+impl SerializeAs<CharacterCode> for CodeName {
+  fn serialize_as<S: Serializer>(source: &CharacterCode, serializer: S) -> Result<S::Ok, S::Error> {
+    if serializer.is_human_readable() {
+      serializer.serialize_str(source.name())
+    } else {
+      source.serialize(serializer)
+    }
+  }
+}
+
+impl<'de> DeserializeAs<'de, CharacterCode> for CodeName {
+  fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<CharacterCode, D::Error> {
+    if deserializer.is_human_readable() {
+      deserializer.deserialize_any(CodeVisitor)
+    } else {
+      deserializer.deserialize_u8(CodeVisitor)
+    }
+  }
+}
+
+/// a [`serde_with`] adapter that serializes a [`BoardData`] or [`DynBoardData`] as an array of row strings
+/// instead of nested arrays of numbers.
+///
+/// every row is written with one character per cell. the color tiles, and any code that is not a
+/// character, are written as escapes such as `{63}`. when deserializing, lowercase letters, the color
+/// emoji, and named escapes such as `{red}` are also accepted, and short rows are padded with blanks.
+/// escapes of codes that are not on the board are read as they are, so every board reads back the same.
+///
+/// # example
+/// ```
+/// #[serde_as]
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///   #[serde_as(as = "BoardRows")]
+///   welcome: NoteBoard, // ["{63}{63}{63}", "   WELCOME", "{63}{63}{63}"]
+/// }
+/// ```
+pub struct BoardRows;
+
+/// writes rows of character codes as row strings
+fn write_rows<'a>(rows: impl Iterator<Item = &'a [u8]>) -> Vec<String> {
+  rows
+    .map(|row| {
+      let mut line = String::new();
+      write_row(&mut line, row);
+      line
+    })
+    .collect()
+}
+
+impl<const ROWS: usize, const COLS: usize> SerializeAs<BoardData<ROWS, COLS>> for BoardRows {
+  fn serialize_as<S: Serializer>(source: &BoardData<ROWS, COLS>, serializer: S) -> Result<S::Ok, S::Error> {
+    write_rows(source.0.iter().map(|row| &row[..])).serialize(serializer)
+  }
+}
+
+impl<'de, const ROWS: usize, const COLS: usize> DeserializeAs<'de, BoardData<ROWS, COLS>> for BoardRows {
+  fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<BoardData<ROWS, COLS>, D::Error> {
+    let lines = Vec::<String>::deserialize(deserializer)?;
+    if lines.len() != ROWS {
+      return Err(serde::de::Error::custom(if lines.len() > ROWS {
+        BoardError::TooManyRows {
+          row: ROWS,
+          expected: ROWS,
+        }
+      } else {
+        BoardError::TooFewRows {
+          found: lines.len(),
+          expected: ROWS,
+        }
+      }));
+    }
+
+    let mut board = BoardData::<ROWS, COLS>::default();
+    for (row, (line, cells)) in lines.iter().zip(board.0.iter_mut()).enumerate() {
+      read_row(line, row, cells).map_err(serde::de::Error::custom)?;
+    }
+
+    Ok(board)
+  }
+}
+
+impl SerializeAs<DynBoardData> for BoardRows {
+  fn serialize_as<S: Serializer>(source: &DynBoardData, serializer: S) -> Result<S::Ok, S::Error> {
+    write_rows(source.iter_rows()).serialize(serializer)
+  }
+}
+
+impl<'de> DeserializeAs<'de, DynBoardData> for BoardRows {
+  /// deserializes the rows of a [`DynBoardData`]. the board is as wide as its longest row
+  fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<DynBoardData, D::Error> {
+    let rows = Vec::<String>::deserialize(deserializer)?
+      .iter()
+      .enumerate()
+      .map(|(row, line)| parse_row(line, row))
+      .collect::<Result<Vec<_>, _>>()
+      .map_err(serde::de::Error::custom)?;
+
+    let cols = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let mut board = DynBoardData::try_new(rows.len(), cols).map_err(serde::de::Error::custom)?;
+    for (codes, cells) in rows.iter().zip(board.cells_mut().chunks_mut(cols.max(1))) {
+      cells[..codes.len()].copy_from_slice(codes);
+    }
+
+    Ok(board)
+  }
+}
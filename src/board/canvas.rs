@@ -0,0 +1,225 @@
+use super::{transliterate, BoardData, BoardError, CharacterCode};
+
+/// the code written to a cell for `code`. [`CharacterCode::Newline`] cannot be shown in a cell, so it is
+/// written as a blank, the same as in VBML
+pub(crate) fn cell_code(code: CharacterCode) -> u8 {
+  match code {
+    CharacterCode::Newline => CharacterCode::Blank.into(),
+    code => code.into(),
+  }
+}
+
+/// a rectangular region of a board, used by the drawing methods of [`BoardData`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+  /// the top row of the region
+  pub row: usize,
+  /// the left column of the region
+  pub col: usize,
+  /// the number of rows in the region
+  pub height: usize,
+  /// the number of columns in the region
+  pub width: usize,
+}
+
+impl Rect {
+  /// creates a new [`Rect`] with its top left corner at `row`, `col`
+  pub fn new(row: usize, col: usize, height: usize, width: usize) -> Self {
+    Rect {
+      row,
+      col,
+      height,
+      width,
+    }
+  }
+
+  /// whether the region has no cells
+  pub fn is_empty(&self) -> bool {
+    self.height == 0 || self.width == 0
+  }
+
+  /// iterates over the positions of the cells in the region as `(row, col)`, row by row
+  pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> {
+    let Rect {
+      row,
+      col,
+      height,
+      width,
+    } = *self;
+
+    (row..row.saturating_add(height)).flat_map(move |r| (col..col.saturating_add(width)).map(move |c| (r, c)))
+  }
+
+  /// checks that the region lies within a `rows`x`cols` board
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] with the bottom right corner of the region if it does not fit
+  pub(crate) fn check(&self, rows: usize, cols: usize) -> Result<(), BoardError> {
+    if self.is_empty() {
+      return Ok(());
+    }
+
+    let last_row = self.row.saturating_add(self.height - 1);
+    let last_col = self.col.saturating_add(self.width - 1);
+    if last_row >= rows || last_col >= cols {
+      return Err(BoardError::OutOfBounds {
+        row: last_row,
+        col: last_col,
+      });
+    }
+
+    Ok(())
+  }
+}
+
+/// drawing methods for composing boards. positions are `(row, col)` and zero-indexed.
+///
+/// every method checks its bounds before changing the board, so a method that returns
+/// [`BoardError::OutOfBounds`] leaves the board untouched.
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// a [`Rect`] covering the whole board
+  pub fn bounds(&self) -> Rect {
+    Rect::new(0, 0, ROWS, COLS)
+  }
+
+  /// gets the [`CharacterCode`] at `row`, `col`, or `None` if it is out of bounds
+  pub fn cell(&self, row: usize, col: usize) -> Option<CharacterCode> {
+    self
+      .0
+      .get(row)?
+      .get(col)
+      .map(|code| CharacterCode::from_u8_lossy(*code))
+  }
+
+  /// sets the [`CharacterCode`] at `row`, `col`. [`CharacterCode::Newline`] is written as a blank, here
+  /// and in every other drawing method
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if `row` or `col` is out of bounds
+  pub fn set_cell(&mut self, row: usize, col: usize, code: CharacterCode) -> Result<(), BoardError> {
+    Rect::new(row, col, 1, 1).check(ROWS, COLS)?;
+
+    self.0[row][col] = cell_code(code);
+    Ok(())
+  }
+
+  /// writes a string on a single row starting at `row`, `col`. the text is converted with
+  /// [`super::transliterate()`], and does not wrap.
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the text does not fit on the row
+  pub fn write_text(&mut self, row: usize, col: usize, text: &str) -> Result<(), BoardError> {
+    self.write_codes(row, col, &transliterate(text).codes())
+  }
+
+  /// writes a slice of [`CharacterCode`]s on a single row starting at `row`, `col`
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the codes do not fit on the row
+  pub fn write_codes(&mut self, row: usize, col: usize, codes: &[CharacterCode]) -> Result<(), BoardError> {
+    Rect::new(row, col, 1, codes.len()).check(ROWS, COLS)?;
+
+    for (offset, code) in codes.iter().enumerate() {
+      self.0[row][col + offset] = cell_code(*code);
+    }
+    Ok(())
+  }
+
+  /// fills a region with a single [`CharacterCode`]
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the region does not fit on the board
+  pub fn fill_rect(&mut self, rect: Rect, code: CharacterCode) -> Result<(), BoardError> {
+    rect.check(ROWS, COLS)?;
+
+    for (row, col) in rect.cells() {
+      self.0[row][col] = cell_code(code);
+    }
+    Ok(())
+  }
+
+  /// fills the whole board with a single [`CharacterCode`]
+  pub fn fill(&mut self, code: CharacterCode) {
+    self.0 = [[cell_code(code); COLS]; ROWS];
+  }
+
+  /// draws a horizontal line of `len` cells starting at `row`, `col` and extending right
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the line does not fit on the board
+  pub fn draw_hline(&mut self, row: usize, col: usize, len: usize, code: CharacterCode) -> Result<(), BoardError> {
+    self.fill_rect(Rect::new(row, col, 1, len), code)
+  }
+
+  /// draws a vertical line of `len` cells starting at `row`, `col` and extending down
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the line does not fit on the board
+  pub fn draw_vline(&mut self, row: usize, col: usize, len: usize, code: CharacterCode) -> Result<(), BoardError> {
+    self.fill_rect(Rect::new(row, col, len, 1), code)
+  }
+
+  /// draws the outline of a region, one cell thick, leaving the inside untouched
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the region does not fit on the board
+  pub fn draw_border(&mut self, rect: Rect, code: CharacterCode) -> Result<(), BoardError> {
+    rect.check(ROWS, COLS)?;
+    if rect.is_empty() {
+      return Ok(());
+    }
+
+    let last_row = rect.row + rect.height - 1;
+    let last_col = rect.col + rect.width - 1;
+    for (row, col) in rect.cells() {
+      if row == rect.row || row == last_row || col == rect.col || col == last_col {
+        self.0[row][col] = cell_code(code);
+      }
+    }
+    Ok(())
+  }
+
+  /// copies another board onto this one with its top left corner at `row`, `col`.
+  ///
+  /// if `transparent` is provided, cells of `source` with that code are skipped and leave
+  /// this board unchanged.
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if `source` does not fit on the board at `row`, `col`
+  pub fn blit<const SRC_ROWS: usize, const SRC_COLS: usize>(
+    &mut self,
+    source: &BoardData<SRC_ROWS, SRC_COLS>,
+    row: usize,
+    col: usize,
+    transparent: Option<CharacterCode>,
+  ) -> Result<(), BoardError> {
+    self.blit_region(source, source.bounds(), row, col, transparent)
+  }
+
+  /// copies a region of another board onto this one with its top left corner at `row`, `col`.
+  /// see [`BoardData::blit`] for `transparent`.
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if `region` does not fit on `source`, or the region does not fit
+  ///   on the board at `row`, `col`
+  pub fn blit_region<const SRC_ROWS: usize, const SRC_COLS: usize>(
+    &mut self,
+    source: &BoardData<SRC_ROWS, SRC_COLS>,
+    region: Rect,
+    row: usize,
+    col: usize,
+    transparent: Option<CharacterCode>,
+  ) -> Result<(), BoardError> {
+    region.check(SRC_ROWS, SRC_COLS)?;
+    Rect::new(row, col, region.height, region.width).check(ROWS, COLS)?;
+
+    let transparent = transparent.map(u8::from);
+    for (src_row, src_col) in region.cells() {
+      let code = source.0[src_row][src_col];
+      if Some(code) != transparent {
+        self.0[row + src_row - region.row][col + src_col - region.col] = code;
+      }
+    }
+    Ok(())
+  }
+}
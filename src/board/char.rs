@@ -1,5 +1,13 @@
+use serde::{
+  de::{self, Visitor},
+  Deserialize, Deserializer, Serialize, Serializer,
+};
+use thiserror::Error;
+
+use super::transliterate;
+
 /// the character codes that can be displayed on the Vestaboard
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum CharacterCode {
   Blank = 0,
   A = 1,
@@ -70,10 +78,15 @@ pub enum CharacterCode {
   Newline = 100,
 }
 
-impl From<u8> for CharacterCode {
+impl TryFrom<u8> for CharacterCode {
+  type Error = CharacterCodeError;
+
   /// converts a `u8` to a [`CharacterCode`]
-  fn from(code: u8) -> CharacterCode {
-    match code {
+  ///
+  /// # errors
+  /// - [`CharacterCodeError::InvalidCode`] if the code is not a [`CharacterCode`]
+  fn try_from(code: u8) -> Result<Self, Self::Error> {
+    let code = match code {
       0 => CharacterCode::Blank,
       1 => CharacterCode::A,
       2 => CharacterCode::B,
@@ -141,8 +154,10 @@ impl From<u8> for CharacterCode {
       70 => CharacterCode::Black,
       71 => CharacterCode::Filled,
       100 => CharacterCode::Newline,
-      _ => CharacterCode::Blank,
-    }
+      _ => return Err(CharacterCodeError::InvalidCode(code)),
+    };
+
+    Ok(code)
   }
 }
 
@@ -160,10 +175,188 @@ impl From<CharacterCode> for char {
   }
 }
 
-impl From<char> for CharacterCode {
-  /// converts a `char` to a [`CharacterCode`]
-  fn from(c: char) -> Self {
-    char_to_code(c.to_ascii_uppercase()).into()
+impl TryFrom<char> for CharacterCode {
+  type Error = CharacterCodeError;
+
+  /// converts a `char` to a [`CharacterCode`]. lowercase letters are converted to their uppercase
+  /// [`CharacterCode`], and nothing else is transliterated.
+  ///
+  /// # errors
+  /// - [`CharacterCodeError::InvalidChar`] if the character is not on the board
+  fn try_from(c: char) -> Result<Self, Self::Error> {
+    char_to_code(c.to_ascii_uppercase())
+      .map(CharacterCode::from_u8_lossy)
+      .ok_or(CharacterCodeError::InvalidChar(c))
+  }
+}
+
+impl CharacterCode {
+  /// converts a `u8` to a [`CharacterCode`], using [`CharacterCode::Blank`] for codes that are not a
+  /// [`CharacterCode`]. use [`CharacterCode::try_from`] to catch invalid codes instead.
+  pub fn from_u8_lossy(code: u8) -> Self {
+    CharacterCode::try_from(code).unwrap_or(CharacterCode::Blank)
+  }
+
+  /// converts a `char` to a [`CharacterCode`]. characters that are not on the board are transliterated
+  /// to a single character where possible, such as `é` to [`CharacterCode::E`] or `’` to
+  /// [`CharacterCode::SingleQuote`]. anything else is [`CharacterCode::Blank`]. use
+  /// [`CharacterCode::try_from`] to catch characters that are not on the board instead.
+  ///
+  /// to transliterate whole strings, including symbols that are spelled out with several characters,
+  /// use [`super::transliterate()`].
+  pub fn from_char_lossy(c: char) -> Self {
+    let code = char_to_code(c.to_ascii_uppercase()).or_else(|| {
+      let mut substitute = transliterate::substitute(c)?.chars();
+      match (substitute.next(), substitute.next()) {
+        (Some(c), None) => char_to_code(c),
+        _ => None,
+      }
+    });
+
+    code.map_or(CharacterCode::Blank, CharacterCode::from_u8_lossy)
+  }
+
+  /// whether a `u8` is a code that can be displayed in a board cell. every [`CharacterCode`] except
+  /// [`CharacterCode::Newline`] can be.
+  pub fn is_cell_code(code: u8) -> bool {
+    CharacterCode::try_from(code).is_ok_and(|code| code != CharacterCode::Newline)
+  }
+
+  /// the stable name of the code, as used by [`std::str::FromStr`] and [`super::CodeName`]. letters and
+  /// digits are named by themselves, such as `A` and `1`, and everything else has a lowercase name, such as
+  /// `blank`, `degree`, `red`, or `left-paren`
+  pub fn name(&self) -> &'static str {
+    match self {
+      CharacterCode::Blank => "blank",
+      CharacterCode::A => "A",
+      CharacterCode::B => "B",
+      CharacterCode::C => "C",
+      CharacterCode::D => "D",
+      CharacterCode::E => "E",
+      CharacterCode::F => "F",
+      CharacterCode::G => "G",
+      CharacterCode::H => "H",
+      CharacterCode::I => "I",
+      CharacterCode::J => "J",
+      CharacterCode::K => "K",
+      CharacterCode::L => "L",
+      CharacterCode::M => "M",
+      CharacterCode::N => "N",
+      CharacterCode::O => "O",
+      CharacterCode::P => "P",
+      CharacterCode::Q => "Q",
+      CharacterCode::R => "R",
+      CharacterCode::S => "S",
+      CharacterCode::T => "T",
+      CharacterCode::U => "U",
+      CharacterCode::V => "V",
+      CharacterCode::W => "W",
+      CharacterCode::X => "X",
+      CharacterCode::Y => "Y",
+      CharacterCode::Z => "Z",
+      CharacterCode::One => "1",
+      CharacterCode::Two => "2",
+      CharacterCode::Three => "3",
+      CharacterCode::Four => "4",
+      CharacterCode::Five => "5",
+      CharacterCode::Six => "6",
+      CharacterCode::Seven => "7",
+      CharacterCode::Eight => "8",
+      CharacterCode::Nine => "9",
+      CharacterCode::Zero => "0",
+      CharacterCode::ExclamationMark => "exclamation",
+      CharacterCode::AtSign => "at",
+      CharacterCode::PoundSign => "pound",
+      CharacterCode::DollarSign => "dollar",
+      CharacterCode::LeftParen => "left-paren",
+      CharacterCode::RightParen => "right-paren",
+      CharacterCode::Hyphen => "hyphen",
+      CharacterCode::PlusSign => "plus",
+      CharacterCode::Ampersand => "ampersand",
+      CharacterCode::EqualsSign => "equals",
+      CharacterCode::Semicolon => "semicolon",
+      CharacterCode::Colon => "colon",
+      CharacterCode::SingleQuote => "single-quote",
+      CharacterCode::DoubleQuote => "double-quote",
+      CharacterCode::PercentSign => "percent",
+      CharacterCode::Comma => "comma",
+      CharacterCode::Period => "period",
+      CharacterCode::Slash => "slash",
+      CharacterCode::QuestionMark => "question",
+      CharacterCode::DegreeSign => "degree",
+      CharacterCode::Red => "red",
+      CharacterCode::Orange => "orange",
+      CharacterCode::Yellow => "yellow",
+      CharacterCode::Green => "green",
+      CharacterCode::Blue => "blue",
+      CharacterCode::Violet => "violet",
+      CharacterCode::White => "white",
+      CharacterCode::Black => "black",
+      CharacterCode::Filled => "filled",
+      CharacterCode::Newline => "newline",
+    }
+  }
+}
+
+impl std::str::FromStr for CharacterCode {
+  type Err = CharacterCodeError;
+
+  /// looks up a code by its [`CharacterCode::name`]. names are case-insensitive
+  ///
+  /// # errors
+  /// - [`CharacterCodeError::UnknownName`] if there is no code with the name
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    (0..=u8::MAX)
+      .filter_map(|code| CharacterCode::try_from(code).ok())
+      .find(|code| code.name().eq_ignore_ascii_case(s))
+      .ok_or_else(|| CharacterCodeError::UnknownName(s.to_string()))
+  }
+}
+
+impl Serialize for CharacterCode {
+  /// serializes the code as its number. use [`super::CodeName`] to serialize it by name
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u8((*self).into())
+  }
+}
+
+impl<'de> Deserialize<'de> for CharacterCode {
+  /// deserializes a code from either its number or its [`CharacterCode::name`]. formats that are not
+  /// human readable, such as bincode, only accept the number
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    if deserializer.is_human_readable() {
+      deserializer.deserialize_any(CodeVisitor)
+    } else {
+      deserializer.deserialize_u8(CodeVisitor)
+    }
+  }
+}
+
+/// visits a character code given as a number or a name
+pub(crate) struct CodeVisitor;
+
+impl<'de> Visitor<'de> for CodeVisitor {
+  type Value = CharacterCode;
+
+  fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    f.write_str("a character code number or name")
+  }
+
+  fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+    u8::try_from(value)
+      .ok()
+      .and_then(|code| CharacterCode::try_from(code).ok())
+      .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+  }
+
+  fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+    u64::try_from(value)
+      .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+      .and_then(|value| self.visit_u64(value))
+  }
+
+  fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+    value.parse().map_err(E::custom)
   }
 }
 
@@ -179,7 +372,7 @@ impl std::fmt::Display for CharacterCode {
 }
 
 /// converts a `u8` character code to a `char`
-fn code_to_char(code: u8) -> char {
+pub(crate) fn code_to_char(code: u8) -> char {
   match code {
     0 => ' ',
     1 => 'A',
@@ -246,16 +439,17 @@ fn code_to_char(code: u8) -> char {
     68 => '🟪',
     69 => '⬜',
     70 => '⬛',
-    // todo: is FILLED necessary? - https://github.com/Vestaboard/vbml/blob/a8a3f9d9b1fbc03d21743fd631cc2d15a3fa206e/src/characterCodesToAscii.ts#L14
-    71 => '⬜',
+    // filled is the opposite of the blank bits, so its color depends on the edition.
+    // see BoardEdition::to_char for the tile it looks like
+    71 => '█',
     100 => '\n',
     _ => ' ',
   }
 }
 
-/// converts a `char`` to a `u8` character code
-fn char_to_code(c: char) -> u8 {
-  match c {
+/// converts a `char` to a `u8` character code, or `None` if the character is not on the board
+pub(crate) fn char_to_code(c: char) -> Option<u8> {
+  let code = match c {
     ' ' => 0,
     'A' => 1,
     'B' => 2,
@@ -321,7 +515,27 @@ fn char_to_code(c: char) -> u8 {
     '🟪' => 68,
     '⬜' => 69,
     '⬛' => 70,
+    '█' => 71,
     '\n' => 100,
-    _ => 0,
-  }
+    _ => return None,
+  };
+
+  Some(code)
+}
+
+/// error type for strict conversions into a [`CharacterCode`]
+/// - [`CharacterCodeError::InvalidCode`] if a `u8` is not a character code
+/// - [`CharacterCodeError::InvalidChar`] if a `char` is not on the board
+/// - [`CharacterCodeError::UnknownName`] if there is no code with a name
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CharacterCodeError {
+  /// the `u8` is not a character code
+  #[error("invalid character code: {0}")]
+  InvalidCode(u8),
+  /// the `char` is not on the board
+  #[error("character is not on the board: {0:?}")]
+  InvalidChar(char),
+  /// there is no code with the name, see [`CharacterCode::name`]
+  #[error("unknown character code name: {0}")]
+  UnknownName(String),
 }
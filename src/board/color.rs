@@ -0,0 +1,75 @@
+use super::{BoardEdition, CharacterCode};
+
+/// an 8-bit rgb color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl std::fmt::Display for Rgb {
+  /// writes the color as a hex string, such as `#da291c`
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+  }
+}
+
+/// the color of the frame around a black edition board and the space between its bits
+const BEZEL_COLOR: Rgb = Rgb(0x0d, 0x0d, 0x0d);
+/// the color of a blank flap on a black edition board
+const FLAP_COLOR: Rgb = Rgb(0x1c, 0x1c, 0x1c);
+/// the color of the characters on a black edition board
+const TEXT_COLOR: Rgb = Rgb(0xf2, 0xf2, 0xf2);
+
+/// the color of the frame around a white edition board and the space between its bits
+const WHITE_BEZEL_COLOR: Rgb = Rgb(0xd9, 0xd9, 0xd6);
+/// the color of a blank flap on a white edition board
+const WHITE_FLAP_COLOR: Rgb = Rgb(0xf0, 0xf0, 0xed);
+/// the color of the characters on a white edition board
+const WHITE_TEXT_COLOR: Rgb = Rgb(0x1c, 0x1c, 0x1c);
+
+/// gets the color of a color tile on a black edition board, or `None` if the code is not a color
+/// (codes 63-71). see [`BoardEdition::tile_color`] for other editions
+pub fn tile_color(code: CharacterCode) -> Option<Rgb> {
+  BoardEdition::Black.tile_color(code)
+}
+
+impl BoardEdition {
+  /// gets the color of a color tile on this edition, or `None` if the code is not a color (codes 63-71).
+  /// [`CharacterCode::Filled`] is the opposite of the blank bits, see [`BoardEdition::filled`]
+  pub fn tile_color(&self, code: CharacterCode) -> Option<Rgb> {
+    match code {
+      CharacterCode::Red => Some(Rgb(0xda, 0x29, 0x1c)),
+      CharacterCode::Orange => Some(Rgb(0xff, 0x75, 0x00)),
+      CharacterCode::Yellow => Some(Rgb(0xff, 0xb8, 0x1c)),
+      CharacterCode::Green => Some(Rgb(0x00, 0x9a, 0x44)),
+      CharacterCode::Blue => Some(Rgb(0x00, 0x84, 0xd5)),
+      CharacterCode::Violet => Some(Rgb(0x70, 0x2f, 0x8a)),
+      CharacterCode::White => Some(Rgb(0xff, 0xff, 0xff)),
+      CharacterCode::Black => Some(Rgb(0x00, 0x00, 0x00)),
+      CharacterCode::Filled => self.tile_color(self.filled()),
+      _ => None,
+    }
+  }
+
+  /// the color of the frame around the board and the space between its bits
+  pub fn bezel_color(&self) -> Rgb {
+    match self {
+      BoardEdition::Black => BEZEL_COLOR,
+      BoardEdition::White => WHITE_BEZEL_COLOR,
+    }
+  }
+
+  /// the color of a blank flap
+  pub fn flap_color(&self) -> Rgb {
+    match self {
+      BoardEdition::Black => FLAP_COLOR,
+      BoardEdition::White => WHITE_FLAP_COLOR,
+    }
+  }
+
+  /// the color of the characters
+  pub fn text_color(&self) -> Rgb {
+    match self {
+      BoardEdition::Black => TEXT_COLOR,
+      BoardEdition::White => WHITE_TEXT_COLOR,
+    }
+  }
+}
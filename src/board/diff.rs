@@ -0,0 +1,203 @@
+use serde::{Deserialize, Serialize};
+
+use super::{canvas::Rect, BoardData, BoardError, CharacterCode};
+
+/// a single cell that differs between two boards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CellChange {
+  /// the row of the cell
+  pub row: usize,
+  /// the column of the cell
+  pub col: usize,
+  /// the code of the cell on the old board
+  pub old: CharacterCode,
+  /// the code of the cell on the new board
+  pub new: CharacterCode,
+}
+
+/// the changes between two boards, created with [`BoardData::diff`]. changes are ordered row by row.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BoardDiff {
+  changes: Vec<CellChange>,
+  /// the raw new code of every change, which may not be a valid [`CharacterCode`]
+  codes: Vec<u8>,
+}
+
+impl BoardDiff {
+  /// the changed cells, ordered row by row
+  pub fn changes(&self) -> &[CellChange] {
+    &self.changes
+  }
+
+  /// whether the boards are identical, in which case writing the new board would be a no-op
+  pub fn is_empty(&self) -> bool {
+    self.changes.is_empty()
+  }
+
+  /// the number of changed cells
+  pub fn changed_cells(&self) -> usize {
+    self.changes.len()
+  }
+
+  /// the number of rows with at least one changed cell
+  pub fn changed_rows(&self) -> usize {
+    let mut rows = self.changes.iter().map(|change| change.row).collect::<Vec<_>>();
+    rows.dedup();
+    rows.len()
+  }
+
+  /// the number of columns with at least one changed cell
+  pub fn changed_cols(&self) -> usize {
+    let mut cols = self.changes.iter().map(|change| change.col).collect::<Vec<_>>();
+    cols.sort_unstable();
+    cols.dedup();
+    cols.len()
+  }
+
+  /// converts the diff into a compact [`BoardPatch`] that turns the old board into the new board. the
+  /// patch writes the raw codes of the new board, even ones that cannot be displayed
+  pub fn to_patch(&self) -> BoardPatch {
+    let mut runs: Vec<PatchRun> = Vec::new();
+
+    for (change, &code) in self.changes.iter().zip(&self.codes) {
+      match runs.last_mut() {
+        Some(run) if run.row == change.row && run.col + run.codes.len() == change.col => run.codes.push(code),
+        _ => runs.push(PatchRun {
+          row: change.row,
+          col: change.col,
+          codes: vec![code],
+        }),
+      }
+    }
+
+    BoardPatch { runs }
+  }
+}
+
+impl std::fmt::Display for BoardDiff {
+  /// writes one line per changed cell as `(row, col): 'old' -> 'new'`
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for change in &self.changes {
+      writeln!(
+        f,
+        "({}, {}): {:?} -> {:?}",
+        change.row,
+        change.col,
+        char::from(change.old),
+        char::from(change.new)
+      )?;
+    }
+
+    Ok(())
+  }
+}
+
+/// a run of consecutive changed cells on a single row of a [`BoardPatch`]
+///
+/// serializes as a compact `[row, col, [codes...]]` array
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "(usize, usize, Vec<u8>)", into = "(usize, usize, Vec<u8>)")]
+pub struct PatchRun {
+  /// the row of the run
+  pub row: usize,
+  /// the column of the first cell of the run
+  pub col: usize,
+  /// the new character codes of the run, left to right
+  pub codes: Vec<u8>,
+}
+
+impl From<(usize, usize, Vec<u8>)> for PatchRun {
+  fn from((row, col, codes): (usize, usize, Vec<u8>)) -> Self {
+    PatchRun { row, col, codes }
+  }
+}
+
+impl From<PatchRun> for (usize, usize, Vec<u8>) {
+  fn from(run: PatchRun) -> Self {
+    (run.row, run.col, run.codes)
+  }
+}
+
+/// a compact set of cell writes that can be applied to a board, created with [`BoardDiff::to_patch`].
+///
+/// serializes as an array of [`PatchRun`]s, such as `[[2,5,[8,9]]]` to write "HI" at row 2, column 5.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct BoardPatch {
+  /// the runs of changed cells
+  pub runs: Vec<PatchRun>,
+}
+
+impl BoardPatch {
+  /// whether the patch has no changes
+  pub fn is_empty(&self) -> bool {
+    self.runs.iter().all(|run| run.codes.is_empty())
+  }
+
+  /// applies the patch to a board. runs without codes change nothing and are skipped, wherever they are.
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if a run does not fit on the board. the board is left untouched.
+  pub fn apply<const ROWS: usize, const COLS: usize>(
+    &self,
+    board: &mut BoardData<ROWS, COLS>,
+  ) -> Result<(), BoardError> {
+    let runs = || self.runs.iter().filter(|run| !run.codes.is_empty());
+    for run in runs() {
+      Rect::new(run.row, run.col, 1, run.codes.len()).check(ROWS, COLS)?;
+    }
+
+    for run in runs() {
+      board.0[run.row][run.col..run.col + run.codes.len()].copy_from_slice(&run.codes);
+    }
+    Ok(())
+  }
+}
+
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// compares this board (the old board) to `other` (the new board). cells are compared by their raw
+  /// codes, and codes are converted with [`CharacterCode::from_u8_lossy`] for the [`CellChange`]s, so a
+  /// change between two codes that cannot be displayed has a blank `old` and `new`.
+  ///
+  /// # example
+  /// ```
+  /// let diff = current.diff(&next);
+  /// if !diff.is_empty() {
+  ///   api.write(next).await?;
+  /// }
+  /// ```
+  pub fn diff(&self, other: &BoardData<ROWS, COLS>) -> BoardDiff {
+    let (changes, codes) = self
+      .0
+      .iter()
+      .zip(other.0.iter())
+      .enumerate()
+      .flat_map(|(row, (old_row, new_row))| {
+        old_row
+          .iter()
+          .zip(new_row.iter())
+          .enumerate()
+          .filter(|(_, (old, new))| old != new)
+          .map(move |(col, (old, new))| {
+            let change = CellChange {
+              row,
+              col,
+              old: CharacterCode::from_u8_lossy(*old),
+              new: CharacterCode::from_u8_lossy(*new),
+            };
+            (change, *new)
+          })
+      })
+      .unzip();
+
+    BoardDiff { changes, codes }
+  }
+
+  /// applies a [`BoardPatch`] to this board. see [`BoardPatch::apply`]
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if a run does not fit on the board. the board is left untouched.
+  pub fn apply_patch(&mut self, patch: &BoardPatch) -> Result<(), BoardError> {
+    patch.apply(self)
+  }
+}
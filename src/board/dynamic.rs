@@ -0,0 +1,192 @@
+use serde::{Deserialize, Serialize};
+
+use super::{fmt_rows, parse::BoardParser, Board, BoardData, BoardError, CodeHandling};
+
+/// a Vestaboard representation whose dimensions are chosen at runtime.
+///
+/// use this when the size of the board is not known at compile time, such as when a VBML
+/// document sets `style.height` and `style.width`. converts to and from the const-generic
+/// [`BoardData<ROWS, COLS>`].
+///
+/// serializes and deserializes as a nested array of character codes, the same as [`BoardData`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "Vec<Vec<u8>>", into = "Vec<Vec<u8>>")]
+pub struct DynBoardData {
+  rows: usize,
+  cols: usize,
+  data: Vec<u8>,
+}
+
+impl DynBoardData {
+  /// creates a new [`DynBoardData`] with all values set to [`super::CharacterCode::Blank`] (0)
+  ///
+  /// # panics
+  /// panics if `rows * cols` overflows a `usize`. use [`DynBoardData::try_new`] for sizes from untrusted
+  /// input
+  pub fn new(rows: usize, cols: usize) -> Self {
+    DynBoardData::try_new(rows, cols).expect("board dimensions overflow")
+  }
+
+  /// creates a new [`DynBoardData`] with all values set to [`super::CharacterCode::Blank`] (0)
+  ///
+  /// # errors
+  /// - [`BoardError::TooLarge`] if `rows * cols` overflows a `usize`
+  pub fn try_new(rows: usize, cols: usize) -> Result<Self, BoardError> {
+    let cells = rows.checked_mul(cols).ok_or(BoardError::TooLarge { rows, cols })?;
+
+    Ok(DynBoardData {
+      rows,
+      cols,
+      data: vec![0; cells],
+    })
+  }
+
+  /// the number of rows in the board
+  pub fn rows(&self) -> usize {
+    self.rows
+  }
+
+  /// the number of columns in the board
+  pub fn cols(&self) -> usize {
+    self.cols
+  }
+
+  /// gets the character code at `row`, `col`, or `None` if it is out of bounds
+  pub fn get(&self, row: usize, col: usize) -> Option<u8> {
+    (row < self.rows && col < self.cols).then(|| self.data[row * self.cols + col])
+  }
+
+  /// sets the character code at `row`, `col`
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if `row` or `col` is out of bounds
+  pub fn set(&mut self, row: usize, col: usize, code: u8) -> Result<(), BoardError> {
+    if row >= self.rows || col >= self.cols {
+      return Err(BoardError::OutOfBounds { row, col });
+    }
+
+    self.data[row * self.cols + col] = code;
+    Ok(())
+  }
+
+  /// gets a row of the board, or `None` if it is out of bounds
+  pub fn row(&self, row: usize) -> Option<&[u8]> {
+    (row < self.rows).then(|| &self.data[row * self.cols..(row + 1) * self.cols])
+  }
+
+  /// iterates over the rows of the board
+  pub fn iter_rows(&self) -> impl Iterator<Item = &[u8]> {
+    // a board without columns still has its rows, they are just empty
+    (0..self.rows).map(move |row| &self.data[row * self.cols..(row + 1) * self.cols])
+  }
+
+  /// parses a string into a [`DynBoardData`], choosing how to handle numbers that are not character
+  /// codes. see [`DynBoardData::from_str`](std::str::FromStr::from_str)
+  ///
+  /// # errors
+  /// - the same as [`DynBoardData::from_str`](std::str::FromStr::from_str), except that
+  ///   [`BoardError::InvalidCode`] is only returned with [`CodeHandling::Strict`]
+  pub fn parse_with(s: &str, handling: CodeHandling) -> Result<Self, BoardError> {
+    BoardParser::new(s).parse(None, handling)?.try_into()
+  }
+
+  /// the character codes of the board in row-major order
+  pub(crate) fn cells_mut(&mut self) -> &mut [u8] {
+    &mut self.data
+  }
+}
+
+impl TryFrom<Vec<Vec<u8>>> for DynBoardData {
+  type Error = BoardError;
+
+  /// constructs a new [`DynBoardData`] from nested rows of character codes
+  ///
+  /// # errors
+  /// - [`BoardError::InvalidLength`] if the rows are not all the same length
+  fn try_from(value: Vec<Vec<u8>>) -> Result<Self, Self::Error> {
+    let rows = value.len();
+    let cols = value.first().map_or(0, |row| row.len());
+
+    if value.iter().any(|row| row.len() != cols) {
+      return Err(BoardError::InvalidLength);
+    }
+
+    Ok(DynBoardData {
+      rows,
+      cols,
+      data: value.into_iter().flatten().collect(),
+    })
+  }
+}
+
+impl std::str::FromStr for DynBoardData {
+  type Err = BoardError;
+
+  /// attempts to parse a string into a [`DynBoardData`]. input should be the string representation
+  /// of a board as a nested array. the dimensions are taken from the input, and every row must be
+  /// the same length as the first.
+  ///
+  /// # errors
+  /// - [`BoardError::UnexpectedChar`] or [`BoardError::UnexpectedEnd`] if the input is not a nested array
+  /// - [`BoardError::TooManyCols`] or [`BoardError::TooFewCols`] if a row is not the same length as the first
+  /// - [`BoardError::InvalidChar`] if a cell is not a number
+  /// - [`BoardError::InvalidCode`] if a cell is a number that is not a character code
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    DynBoardData::parse_with(s, CodeHandling::Strict)
+  }
+}
+
+impl From<DynBoardData> for Vec<Vec<u8>> {
+  /// converts a [`DynBoardData`] into nested rows of character codes
+  fn from(val: DynBoardData) -> Self {
+    val.iter_rows().map(|row| row.to_vec()).collect()
+  }
+}
+
+impl<const ROWS: usize, const COLS: usize> From<BoardData<ROWS, COLS>> for DynBoardData {
+  /// constructs a new [`DynBoardData`] from a [`BoardData`]
+  fn from(value: BoardData<ROWS, COLS>) -> Self {
+    DynBoardData {
+      rows: ROWS,
+      cols: COLS,
+      data: value.0.iter().flatten().copied().collect(),
+    }
+  }
+}
+
+impl<const ROWS: usize, const COLS: usize> From<Board<ROWS, COLS>> for DynBoardData {
+  /// constructs a new [`DynBoardData`] from a raw [`Board`] type
+  fn from(value: Board<ROWS, COLS>) -> Self {
+    BoardData(value).into()
+  }
+}
+
+impl<const ROWS: usize, const COLS: usize> TryFrom<DynBoardData> for BoardData<ROWS, COLS> {
+  type Error = BoardError;
+
+  /// converts a [`DynBoardData`] into a [`BoardData`] with matching dimensions
+  ///
+  /// # errors
+  /// - [`BoardError::DimensionMismatch`] if the dimensions of the [`DynBoardData`] are not `ROWS`x`COLS`
+  fn try_from(value: DynBoardData) -> Result<Self, Self::Error> {
+    if value.rows != ROWS || value.cols != COLS {
+      return Err(BoardError::DimensionMismatch {
+        expected: (ROWS, COLS),
+        found: (value.rows, value.cols),
+      });
+    }
+
+    let mut board: Board<ROWS, COLS> = [[0; COLS]; ROWS];
+    for (row, values) in board.iter_mut().zip(value.iter_rows()) {
+      row.copy_from_slice(values);
+    }
+
+    Ok(BoardData(board))
+  }
+}
+
+impl std::fmt::Display for DynBoardData {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fmt_rows(f, self.cols, self.iter_rows())
+  }
+}
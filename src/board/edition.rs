@@ -0,0 +1,84 @@
+use super::{BoardData, BoardEdition, CharacterCode, DynBoardData};
+
+impl BoardEdition {
+  /// the color tile that a blank bit looks like on this edition
+  pub fn blank(&self) -> CharacterCode {
+    match self {
+      BoardEdition::Black => CharacterCode::Black,
+      BoardEdition::White => CharacterCode::White,
+    }
+  }
+
+  /// the color tile that [`CharacterCode::Filled`] looks like on this edition. filled is the opposite of
+  /// the blank bits, so it is white on the black edition and black on the white edition
+  pub fn filled(&self) -> CharacterCode {
+    match self {
+      BoardEdition::Black => CharacterCode::White,
+      BoardEdition::White => CharacterCode::Black,
+    }
+  }
+
+  /// converts a [`CharacterCode`] to the `char` it looks like on this edition. this is the same as
+  /// converting it to a `char`, except that [`CharacterCode::Filled`] is drawn as [`BoardEdition::filled`]
+  pub fn to_char(&self, code: CharacterCode) -> char {
+    match code {
+      CharacterCode::Filled => self.filled().into(),
+      code => code.into(),
+    }
+  }
+
+  /// the code that makes a cell designed for `self` look the same on `to`. blanks and
+  /// [`CharacterCode::Filled`] are replaced with the color tile they look like, and color tiles that
+  /// look like a blank on `to` are replaced with [`CharacterCode::Blank`]. characters cannot change
+  /// color, and codes that cannot be displayed are not blanks, so both are kept as they are.
+  fn convert(&self, to: BoardEdition, code: u8) -> u8 {
+    if *self == to {
+      return code;
+    }
+
+    let tile = match CharacterCode::try_from(code) {
+      Ok(CharacterCode::Blank) => self.blank(),
+      Ok(CharacterCode::Filled) => self.filled(),
+      Ok(tile @ (CharacterCode::White | CharacterCode::Black)) => tile,
+      _ => return code,
+    };
+
+    if tile == to.blank() {
+      CharacterCode::Blank.into()
+    } else {
+      tile.into()
+    }
+  }
+}
+
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// rewrites a board designed for the `from` edition so it looks the same on the `to` edition.
+  ///
+  /// blanks become [`BoardEdition::blank`] tiles of `from`, [`CharacterCode::Filled`] becomes a
+  /// [`BoardEdition::filled`] tile of `from`, and any tile that looks like a blank on `to` becomes
+  /// [`CharacterCode::Blank`]. characters keep the text color of the board they are shown on.
+  ///
+  /// # example
+  /// ```
+  /// // a white square on a black background
+  /// board.convert_edition(BoardEdition::Black, BoardEdition::White);
+  /// ```
+  pub fn convert_edition(&mut self, from: BoardEdition, to: BoardEdition) {
+    self
+      .0
+      .iter_mut()
+      .flatten()
+      .for_each(|code| *code = from.convert(to, *code));
+  }
+}
+
+impl DynBoardData {
+  /// rewrites a board designed for the `from` edition so it looks the same on the `to` edition.
+  /// see [`BoardData::convert_edition`]
+  pub fn convert_edition(&mut self, from: BoardEdition, to: BoardEdition) {
+    self
+      .cells_mut()
+      .iter_mut()
+      .for_each(|code| *code = from.convert(to, *code));
+  }
+}
@@ -0,0 +1,215 @@
+use super::{canvas::cell_code, BoardData, BoardError, CharacterCode, Rect};
+
+/// a built-in block font for drawing large text with color tiles, see [`BoardData::draw_big_text`]
+///
+/// both fonts have the digits `0`-`9`, the letters `A`-`Z`, spaces, and the symbols `: . - + / °`. the
+/// large font also has `! ? %`. lowercase letters are drawn as uppercase. glyphs are separated by one
+/// blank column.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BigFont {
+  /// glyphs 3 rows tall and mostly 3 columns wide. a flagship board fits two rows of 5 or 6 glyphs
+  Small,
+  /// glyphs 5 rows tall and mostly 3 columns wide, which reads well on a flagship board
+  #[default]
+  Large,
+}
+
+/// a glyph of a [`BigFont`], one string per row with `#` for a tile and `.` for a blank
+type Glyph = &'static [&'static str];
+
+impl BigFont {
+  /// the number of rows of every glyph
+  pub fn height(&self) -> usize {
+    match self {
+      BigFont::Small => 3,
+      BigFont::Large => 5,
+    }
+  }
+
+  /// measures `text` in this font as `(rows, cols)`, to position it before drawing
+  ///
+  /// # errors
+  /// - [`BoardError::InvalidChar`] if the font has no glyph for a character. `col` is the index of the
+  ///   character in the text
+  pub fn measure(&self, text: &str) -> Result<(usize, usize), BoardError> {
+    let widths = self
+      .glyphs(text)?
+      .iter()
+      .map(|glyph| glyph[0].len())
+      .collect::<Vec<_>>();
+    let cols = widths.iter().sum::<usize>() + widths.len().saturating_sub(1);
+
+    Ok((self.height(), cols))
+  }
+
+  /// looks up the glyph of every character of `text`
+  ///
+  /// # errors
+  /// - [`BoardError::InvalidChar`] if the font has no glyph for a character
+  fn glyphs(&self, text: &str) -> Result<Vec<Glyph>, BoardError> {
+    text
+      .chars()
+      .enumerate()
+      .map(|(index, c)| {
+        let c = c.to_ascii_uppercase();
+        let glyph = match self {
+          BigFont::Small => small_glyph(c),
+          BigFont::Large => large_glyph(c),
+        };
+
+        glyph.ok_or_else(|| BoardError::InvalidChar {
+          row: 0,
+          col: index,
+          value: c.to_string(),
+        })
+      })
+      .collect()
+  }
+}
+
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// draws `text` in a [`BigFont`] with its top left corner at `row`, `col`, using `color` for the tiles
+  /// of every glyph. cells between the tiles are left untouched, so the text can be drawn over a
+  /// background. use [`BigFont::measure`] to center the text.
+  ///
+  /// # example
+  /// ```
+  /// let (rows, cols) = BigFont::Large.measure("12:30")?;
+  /// board.draw_big_text((6 - rows) / 2, (22 - cols) / 2, "12:30", BigFont::Large, CharacterCode::Orange)?;
+  /// ```
+  ///
+  /// # errors
+  /// - [`BoardError::InvalidChar`] if the font has no glyph for a character of the text
+  /// - [`BoardError::OutOfBounds`] if the text does not fit on the board at `row`, `col`
+  pub fn draw_big_text(
+    &mut self,
+    row: usize,
+    col: usize,
+    text: &str,
+    font: BigFont,
+    color: CharacterCode,
+  ) -> Result<(), BoardError> {
+    let (height, width) = font.measure(text)?;
+    Rect::new(row, col, height, width).check(ROWS, COLS)?;
+
+    let tile = cell_code(color);
+    let mut glyph_col = col;
+    for glyph in font.glyphs(text)? {
+      for (glyph_row, line) in glyph.iter().enumerate() {
+        for (offset, _) in line.bytes().enumerate().filter(|(_, dot)| *dot == b'#') {
+          self.0[row + glyph_row][glyph_col + offset] = tile;
+        }
+      }
+      glyph_col += glyph[0].len() + 1;
+    }
+
+    Ok(())
+  }
+}
+
+/// gets the 3 row glyph of a character
+fn small_glyph(c: char) -> Option<Glyph> {
+  let glyph: Glyph = match c {
+    '0' => &["###", "#.#", "###"],
+    '1' => &["##.", ".#.", "###"],
+    '2' => &["##.", ".#.", ".##"],
+    '3' => &["###", ".##", "###"],
+    '4' => &["#.#", "###", "..#"],
+    '5' => &[".##", ".#.", "##."],
+    '6' => &["#..", "###", "###"],
+    '7' => &["###", "..#", "..#"],
+    '8' => &[".##", "###", "##."],
+    '9' => &["###", "###", "..#"],
+    'A' => &[".#.", "###", "#.#"],
+    'B' => &["##.", "###", "###"],
+    'C' => &["###", "#..", "###"],
+    'D' => &["##.", "#.#", "##."],
+    'E' => &["###", "##.", "###"],
+    'F' => &["###", "##.", "#.."],
+    'G' => &["##.", "#.#", "###"],
+    'H' => &["#.#", "###", "#.#"],
+    'I' => &["###", ".#.", "###"],
+    'J' => &["..#", "#.#", "###"],
+    'K' => &["#.#", "##.", "#.#"],
+    'L' => &["#..", "#..", "###"],
+    'M' => &["###", "###", "#.#"],
+    'N' => &["##.", "#.#", "#.#"],
+    'O' => &[".#.", "#.#", ".#."],
+    'P' => &["###", "###", "#.."],
+    'Q' => &[".#.", "#.#", ".##"],
+    'R' => &["##.", "##.", "#.#"],
+    'S' => &[".##", ".#.", "##."],
+    'T' => &["###", ".#.", ".#."],
+    'U' => &["#.#", "#.#", "###"],
+    'V' => &["#.#", "#.#", ".#."],
+    'W' => &["#.#", "###", "###"],
+    'X' => &["#.#", ".#.", "#.#"],
+    'Y' => &["#.#", ".#.", ".#."],
+    'Z' => &["##.", ".#.", ".##"],
+    ' ' => &["..", "..", ".."],
+    ':' => &["#", ".", "#"],
+    '.' => &[".", ".", "#"],
+    '-' => &["...", "###", "..."],
+    '+' => &[".#.", "###", ".#."],
+    '/' => &["..#", ".#.", "#.."],
+    '°' => &["##", "##", ".."],
+    _ => return None,
+  };
+
+  Some(glyph)
+}
+
+/// gets the 5 row glyph of a character
+fn large_glyph(c: char) -> Option<Glyph> {
+  let glyph: Glyph = match c {
+    '0' => &["###", "#.#", "#.#", "#.#", "###"],
+    '1' => &[".#.", "##.", ".#.", ".#.", "###"],
+    '2' => &["###", "..#", "###", "#..", "###"],
+    '3' => &["###", "..#", ".##", "..#", "###"],
+    '4' => &["#.#", "#.#", "###", "..#", "..#"],
+    '5' => &["###", "#..", "###", "..#", "###"],
+    '6' => &["###", "#..", "###", "#.#", "###"],
+    '7' => &["###", "..#", "..#", "..#", "..#"],
+    '8' => &["###", "#.#", "###", "#.#", "###"],
+    '9' => &["###", "#.#", "###", "..#", "###"],
+    'A' => &[".#.", "#.#", "###", "#.#", "#.#"],
+    'B' => &["##.", "#.#", "##.", "#.#", "##."],
+    'C' => &[".##", "#..", "#..", "#..", ".##"],
+    'D' => &["##.", "#.#", "#.#", "#.#", "##."],
+    'E' => &["###", "#..", "##.", "#..", "###"],
+    'F' => &["###", "#..", "##.", "#..", "#.."],
+    'G' => &[".##", "#..", "#.#", "#.#", ".##"],
+    'H' => &["#.#", "#.#", "###", "#.#", "#.#"],
+    'I' => &["###", ".#.", ".#.", ".#.", "###"],
+    'J' => &["..#", "..#", "..#", "#.#", ".#."],
+    'K' => &["#.#", "#.#", "##.", "#.#", "#.#"],
+    'L' => &["#..", "#..", "#..", "#..", "###"],
+    'M' => &["#...#", "##.##", "#.#.#", "#...#", "#...#"],
+    'N' => &["#..#", "##.#", "#.##", "#..#", "#..#"],
+    'O' => &[".#.", "#.#", "#.#", "#.#", ".#."],
+    'P' => &["##.", "#.#", "##.", "#..", "#.."],
+    'Q' => &[".#.", "#.#", "#.#", "##.", ".##"],
+    'R' => &["##.", "#.#", "##.", "#.#", "#.#"],
+    'S' => &[".##", "#..", ".#.", "..#", "##."],
+    'T' => &["###", ".#.", ".#.", ".#.", ".#."],
+    'U' => &["#.#", "#.#", "#.#", "#.#", "###"],
+    'V' => &["#.#", "#.#", "#.#", "#.#", ".#."],
+    'W' => &["#...#", "#...#", "#.#.#", "##.##", "#...#"],
+    'X' => &["#.#", "#.#", ".#.", "#.#", "#.#"],
+    'Y' => &["#.#", "#.#", ".#.", ".#.", ".#."],
+    'Z' => &["###", "..#", ".#.", "#..", "###"],
+    ' ' => &["..", "..", "..", "..", ".."],
+    ':' => &[".", "#", ".", "#", "."],
+    '.' => &[".", ".", ".", ".", "#"],
+    '-' => &["...", "...", "###", "...", "..."],
+    '+' => &["...", ".#.", "###", ".#.", "..."],
+    '/' => &["..#", "..#", ".#.", "#..", "#.."],
+    '°' => &["##", "##", "..", "..", ".."],
+    '!' => &["#", "#", "#", ".", "#"],
+    '?' => &["##.", "..#", ".#.", "...", ".#."],
+    '%' => &["#.#", "..#", ".#.", "#..", "#.#"],
+    _ => return None,
+  };
+
+  Some(glyph)
+}
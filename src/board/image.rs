@@ -0,0 +1,373 @@
+use thiserror::Error;
+
+use super::{BoardData, BoardEdition, CharacterCode};
+
+/// how the colors of an image are spread across the color tiles when it is converted with
+/// [`BoardData::from_rgb`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Dither {
+  /// every cell is the closest color, which keeps flat areas flat
+  #[default]
+  None,
+  /// a 4x4 Bayer pattern is added before picking the closest color, which gives an even cross-hatch
+  Ordered,
+  /// the error of every cell is carried to its neighbors, which keeps gradients smooth
+  FloydSteinberg,
+}
+
+/// options for converting an image to color tiles with [`BoardData::from_rgb`]
+#[derive(Debug, Clone)]
+pub struct ImageOptions {
+  /// how colors between the tiles are drawn
+  pub dither: Dither,
+  /// the codes that cells may be set to. codes that are not color tiles are ignored, and when two codes
+  /// have the same color on the edition, the one earlier in the palette is used.
+  ///
+  /// the default palette is every color tile except [`CharacterCode::Filled`]. filled always has the same
+  /// color as the [`CharacterCode::White`] or [`CharacterCode::Black`] tile of the edition, so it adds no
+  /// color, and leaving it out keeps the image the same on both editions. put it before those tiles to
+  /// draw with the color that stands out from the blank bits of whichever edition shows the board.
+  pub palette: Vec<CharacterCode>,
+  /// the edition whose tile colors are matched, which decides the color of [`CharacterCode::Filled`]
+  pub edition: BoardEdition,
+}
+
+impl Default for ImageOptions {
+  fn default() -> Self {
+    ImageOptions {
+      dither: Dither::None,
+      palette: vec![
+        CharacterCode::Red,
+        CharacterCode::Orange,
+        CharacterCode::Yellow,
+        CharacterCode::Green,
+        CharacterCode::Blue,
+        CharacterCode::Violet,
+        CharacterCode::White,
+        CharacterCode::Black,
+      ],
+      edition: BoardEdition::default(),
+    }
+  }
+}
+
+impl ImageOptions {
+  /// sets how colors between the tiles are drawn
+  pub fn dither(mut self, dither: Dither) -> Self {
+    self.dither = dither;
+    self
+  }
+
+  /// sets the codes that cells may be set to
+  pub fn palette(mut self, palette: impl IntoIterator<Item = CharacterCode>) -> Self {
+    self.palette = palette.into_iter().collect();
+    self
+  }
+
+  /// sets the edition whose tile colors are matched
+  pub fn edition(mut self, edition: BoardEdition) -> Self {
+    self.edition = edition;
+    self
+  }
+}
+
+/// errors that can occur when converting an image to a board
+/// - [`ImageError::Empty`] if the image has no pixels
+/// - [`ImageError::InvalidLength`] if the pixel data does not match the size of the image
+/// - [`ImageError::EmptyPalette`] if the palette has no color tiles
+/// - [`ImageError::InvalidPpm`] if a PPM image cannot be read
+/// - [`ImageError::Png`] if a PNG image cannot be decoded (requires the `image` feature)
+#[derive(Error, Debug)]
+pub enum ImageError {
+  /// the image has no pixels
+  #[error("image has no pixels")]
+  Empty,
+  /// the pixel data does not match the size of the image
+  #[error("expected {expected} bytes of pixel data, found {found}")]
+  InvalidLength { expected: usize, found: usize },
+  /// the palette has no color tiles
+  #[error("palette has no color tiles")]
+  EmptyPalette,
+  /// the PPM image cannot be read
+  #[error("invalid ppm: {0}")]
+  InvalidPpm(&'static str),
+  /// png decoding error, see wrapped [`png::DecodingError`] for more details
+  #[cfg(feature = "image")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+  #[error("failed to decode png: {0}")]
+  Png(#[from] png::DecodingError),
+}
+
+/// how far the ordered dither pattern moves a channel, from half below to half above
+const ORDERED_SPREAD: f32 = 96.0;
+
+/// the 4x4 Bayer matrix for ordered dithering
+const BAYER: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+/// the average color of every cell of a `rows`x`cols` board laid over the image, row by row
+fn average_cells(width: usize, height: usize, pixels: &[u8], rows: usize, cols: usize) -> Vec<[f32; 3]> {
+  // every cell covers at least one pixel, so small images are scaled up
+  let span = |index: usize, cells: usize, size: usize| {
+    let start = index * size / cells;
+    start..(((index + 1) * size / cells).max(start + 1))
+  };
+
+  let mut cells = Vec::with_capacity(rows * cols);
+  for row in 0..rows {
+    let ys = span(row, rows, height);
+    for col in 0..cols {
+      let xs = span(col, cols, width);
+
+      let mut sum = [0u64; 3];
+      for y in ys.clone() {
+        for x in xs.clone() {
+          let index = (y * width + x) * 3;
+          for (channel, value) in sum.iter_mut().zip(&pixels[index..index + 3]) {
+            *channel += *value as u64;
+          }
+        }
+      }
+
+      let count = (ys.len() * xs.len()) as f32;
+      cells.push(sum.map(|channel| channel as f32 / count));
+    }
+  }
+
+  cells
+}
+
+/// the closest color of the palette by distance in rgb, with the error left over
+fn closest(palette: &[(CharacterCode, [f32; 3])], color: [f32; 3]) -> (CharacterCode, [f32; 3]) {
+  let distance = |tile: &[f32; 3]| (0..3).map(|i| (color[i] - tile[i]).powi(2)).sum::<f32>();
+
+  let (code, tile) = palette
+    .iter()
+    .min_by(|(_, a), (_, b)| distance(a).total_cmp(&distance(b)))
+    .copied()
+    .unwrap_or((CharacterCode::Blank, color));
+  (code, [color[0] - tile[0], color[1] - tile[1], color[2] - tile[2]])
+}
+
+/// converts an image with three bytes per pixel to color tiles
+fn convert<const ROWS: usize, const COLS: usize>(
+  width: usize,
+  height: usize,
+  pixels: &[u8],
+  options: &ImageOptions,
+) -> Result<BoardData<ROWS, COLS>, ImageError> {
+  if width == 0 || height == 0 {
+    return Err(ImageError::Empty);
+  }
+  let expected = width.saturating_mul(height).saturating_mul(3);
+  if pixels.len() != expected {
+    return Err(ImageError::InvalidLength {
+      expected,
+      found: pixels.len(),
+    });
+  }
+
+  let palette: Vec<_> = options
+    .palette
+    .iter()
+    .filter_map(|code| {
+      let tile = options.edition.tile_color(*code)?;
+      Some((*code, [tile.0 as f32, tile.1 as f32, tile.2 as f32]))
+    })
+    .collect();
+  if palette.is_empty() {
+    return Err(ImageError::EmptyPalette);
+  }
+
+  let (rows, cols) = (ROWS, COLS);
+  let mut cells = average_cells(width, height, pixels, rows, cols);
+  let mut board = BoardData::<ROWS, COLS>::default();
+
+  for row in 0..rows {
+    for col in 0..cols {
+      let index = row * cols + col;
+      let mut color = cells[index];
+      if options.dither == Dither::Ordered {
+        let offset = ((BAYER[row % 4][col % 4] as f32 + 0.5) / 16.0 - 0.5) * ORDERED_SPREAD;
+        color = color.map(|channel| channel + offset);
+      }
+
+      let (code, error) = closest(&palette, color);
+      board.0[row][col] = code as u8;
+
+      if options.dither == Dither::FloydSteinberg {
+        let mut spread = |row: usize, col: usize, weight: f32| {
+          if row < rows && col < cols {
+            for (channel, error) in cells[row * cols + col].iter_mut().zip(error) {
+              *channel += error * weight;
+            }
+          }
+        };
+        spread(row, col + 1, 7.0 / 16.0);
+        if let Some(left) = col.checked_sub(1) {
+          spread(row + 1, left, 3.0 / 16.0);
+        }
+        spread(row + 1, col, 5.0 / 16.0);
+        spread(row + 1, col + 1, 1.0 / 16.0);
+      }
+    }
+  }
+
+  Ok(board)
+}
+
+/// reads the next whitespace separated token of a PPM header, skipping `#` comments
+fn ppm_token<'a>(bytes: &'a [u8], pos: &mut usize) -> Option<&'a [u8]> {
+  loop {
+    match bytes.get(*pos)? {
+      b'#' => {
+        while bytes.get(*pos).is_some_and(|byte| *byte != b'\n') {
+          *pos += 1;
+        }
+      }
+      byte if byte.is_ascii_whitespace() => *pos += 1,
+      _ => break,
+    }
+  }
+
+  let start = *pos;
+  while bytes.get(*pos).is_some_and(|byte| !byte.is_ascii_whitespace()) {
+    *pos += 1;
+  }
+  Some(&bytes[start..*pos])
+}
+
+/// reads a number of a PPM header
+fn ppm_number(bytes: &[u8], pos: &mut usize, missing: &'static str) -> Result<usize, ImageError> {
+  ppm_token(bytes, pos)
+    .and_then(|token| std::str::from_utf8(token).ok())
+    .and_then(|token| token.parse().ok())
+    .ok_or(ImageError::InvalidPpm(missing))
+}
+
+/// reads a binary (`P6`) or plain (`P3`) PPM image as its width, height, and 8-bit pixels
+fn read_ppm(bytes: &[u8]) -> Result<(usize, usize, Vec<u8>), ImageError> {
+  let mut pos = 0;
+  let magic = ppm_token(bytes, &mut pos).ok_or(ImageError::InvalidPpm("missing magic number"))?;
+  let binary = match magic {
+    b"P6" => true,
+    b"P3" => false,
+    _ => return Err(ImageError::InvalidPpm("not a P3 or P6 image")),
+  };
+
+  let width = ppm_number(bytes, &mut pos, "missing width")?;
+  let height = ppm_number(bytes, &mut pos, "missing height")?;
+  let max = ppm_number(bytes, &mut pos, "missing maximum value")?;
+  if max == 0 || max > u16::MAX as usize {
+    return Err(ImageError::InvalidPpm("maximum value must be between 1 and 65535"));
+  }
+  let scale = |value: usize| -> Result<u8, ImageError> {
+    if value > max {
+      return Err(ImageError::InvalidPpm("sample is larger than the maximum value"));
+    }
+    Ok(((value * 255 + max / 2) / max) as u8)
+  };
+
+  let samples = width.saturating_mul(height).saturating_mul(3);
+  let pixels = if binary {
+    // a single whitespace character separates the header from the pixels
+    let data = bytes.get(pos + 1..).unwrap_or_default();
+    let sample_size = if max > u8::MAX as usize { 2 } else { 1 };
+    let expected = samples.saturating_mul(sample_size);
+    if data.len() < expected {
+      return Err(ImageError::InvalidLength {
+        expected,
+        found: data.len(),
+      });
+    }
+
+    data[..expected]
+      .chunks_exact(sample_size)
+      .map(|sample| scale(sample.iter().fold(0, |value, byte| value << 8 | *byte as usize)))
+      .collect::<Result<Vec<_>, _>>()?
+  } else {
+    let mut pixels = Vec::new();
+    while let Some(token) = ppm_token(bytes, &mut pos) {
+      let value = std::str::from_utf8(token)
+        .ok()
+        .and_then(|token| token.parse().ok())
+        .ok_or(ImageError::InvalidPpm("sample is not a number"))?;
+      pixels.push(scale(value)?);
+    }
+    if pixels.len() < samples {
+      return Err(ImageError::InvalidLength {
+        expected: samples,
+        found: pixels.len(),
+      });
+    }
+    pixels.truncate(samples);
+    pixels
+  };
+
+  Ok((width, height, pixels))
+}
+
+/// decodes a PNG image as its width, height, and 8-bit rgb pixels. alpha is dropped.
+#[cfg(feature = "image")]
+fn read_png(bytes: &[u8]) -> Result<(usize, usize, Vec<u8>), ImageError> {
+  let mut decoder = png::Decoder::new(bytes);
+  decoder.set_transformations(png::Transformations::normalize_to_color8());
+  let mut reader = decoder.read_info()?;
+
+  let mut buffer = vec![0; reader.output_buffer_size()];
+  let info = reader.next_frame(&mut buffer)?;
+  let (width, height) = (info.width as usize, info.height as usize);
+
+  let pixels = buffer[..info.buffer_size()]
+    .chunks_exact(info.line_size)
+    .flat_map(|line| match info.color_type {
+      png::ColorType::Grayscale => line[..width].iter().flat_map(|v| [*v; 3]).collect::<Vec<_>>(),
+      png::ColorType::GrayscaleAlpha => line[..width * 2].chunks_exact(2).flat_map(|p| [p[0]; 3]).collect(),
+      png::ColorType::Rgba => line[..width * 4]
+        .chunks_exact(4)
+        .flat_map(|p| [p[0], p[1], p[2]])
+        .collect(),
+      _ => line[..width * 3].to_vec(),
+    })
+    .collect();
+
+  Ok((width, height, pixels))
+}
+
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// converts an image to color tiles. `pixels` has three bytes per pixel, row by row from the top left.
+  ///
+  /// the image is stretched to fit the board, and every cell is set to the color of the palette closest
+  /// to the average of the pixels it covers. see [`ImageOptions`] for dithering and the palette.
+  ///
+  /// # errors
+  /// - [`ImageError::Empty`] if the image has no pixels
+  /// - [`ImageError::InvalidLength`] if `pixels` is not `width * height * 3` bytes
+  /// - [`ImageError::EmptyPalette`] if the palette has no color tiles
+  pub fn from_rgb(width: u32, height: u32, pixels: &[u8], options: &ImageOptions) -> Result<Self, ImageError> {
+    convert(width as usize, height as usize, pixels, options)
+  }
+
+  /// converts a binary (`P6`) or plain (`P3`) PPM image to color tiles. see [`BoardData::from_rgb`]
+  ///
+  /// # errors
+  /// - [`ImageError::InvalidPpm`] if the header or a sample cannot be read
+  /// - [`ImageError::InvalidLength`] if there are fewer samples than the header describes
+  /// - any error of [`BoardData::from_rgb`]
+  pub fn from_ppm(bytes: &[u8], options: &ImageOptions) -> Result<Self, ImageError> {
+    let (width, height, pixels) = read_ppm(bytes)?;
+    convert(width, height, &pixels, options)
+  }
+
+  /// converts a PNG image to color tiles (requires the `image` feature). transparency is ignored.
+  /// see [`BoardData::from_rgb`]
+  ///
+  /// # errors
+  /// - [`ImageError::Png`] if the PNG cannot be decoded
+  /// - any error of [`BoardData::from_rgb`]
+  #[cfg(feature = "image")]
+  #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
+  pub fn from_png(bytes: &[u8], options: &ImageOptions) -> Result<Self, ImageError> {
+    let (width, height, pixels) = read_png(bytes)?;
+    convert(width, height, &pixels, options)
+  }
+}
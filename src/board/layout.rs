@@ -0,0 +1,307 @@
+use serde::{Deserialize, Serialize};
+
+use super::{transliterate, Board, BoardData, CharacterCode};
+
+/// enum representing the horizontal justification of a component
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Justify {
+  Center,
+  Left,
+  Right,
+  Justified,
+}
+
+/// enum representing the vertical alignment of a component
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Align {
+  Center,
+  Top,
+  Bottom,
+  Justified,
+  Absolute,
+}
+
+/// options for laying out plain text on a board with [`BoardData::from_text`]
+///
+/// the defaults are left justified, top aligned, and wrapped, the same as a VBML template component
+/// without a style.
+#[derive(Debug, Clone)]
+pub struct TextLayout {
+  /// the horizontal justification of the text
+  pub justify: Justify,
+  /// the vertical alignment of the text
+  pub align: Align,
+  /// whether to wrap words onto the next row. if `false`, each line of the text is cut off at the
+  /// edge of the board
+  pub wrap: bool,
+}
+
+impl Default for TextLayout {
+  fn default() -> Self {
+    TextLayout {
+      justify: Justify::Left,
+      align: Align::Top,
+      wrap: true,
+    }
+  }
+}
+
+impl TextLayout {
+  /// a layout that centers the text horizontally and vertically
+  pub fn centered() -> Self {
+    TextLayout {
+      justify: Justify::Center,
+      align: Align::Center,
+      ..Default::default()
+    }
+  }
+
+  /// sets the horizontal justification of the text
+  pub fn justify(mut self, justify: Justify) -> Self {
+    self.justify = justify;
+    self
+  }
+
+  /// sets the vertical alignment of the text
+  pub fn align(mut self, align: Align) -> Self {
+    self.align = align;
+    self
+  }
+
+  /// sets whether to wrap words onto the next row
+  pub fn wrap(mut self, wrap: bool) -> Self {
+    self.wrap = wrap;
+    self
+  }
+}
+
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// lays out plain text on a new board, using the same word wrapping, justification, and alignment
+  /// as a VBML template component that fills the board. the text is converted with [`super::transliterate()`],
+  /// and text that does not fit on the board is dropped.
+  ///
+  /// # example
+  /// ```
+  /// let board: BoardData = BoardData::from_text("hello world", TextLayout::centered());
+  /// ```
+  pub fn from_text(text: &str, layout: TextLayout) -> Self {
+    let text = &transliterate(text).text;
+    let (content_height, content_widest_width, content) = if layout.wrap {
+      wrap_words(text, ROWS, COLS, ROWS)
+    } else {
+      cut_lines(text, ROWS, COLS)
+    };
+
+    let mut board: Board<ROWS, COLS> = [[0; COLS]; ROWS];
+    place_rows(
+      &content,
+      false,
+      content_height.min(ROWS),
+      content_widest_width,
+      ROWS,
+      COLS,
+      Some(&layout.justify),
+      Some(&layout.align),
+      |row, col, code| {
+        if row < ROWS && col < COLS {
+          board[row][col] = code.into();
+        }
+      },
+    );
+
+    BoardData(board)
+  }
+
+  /// lays out plain text centered horizontally and vertically on a new board. see [`BoardData::from_text`]
+  pub fn centered(text: &str) -> Self {
+    BoardData::from_text(text, TextLayout::centered())
+  }
+}
+
+/// wraps text into rows of character codes for a `height`x`width` area. this is the word wrapping
+/// used by VBML template components.
+///
+/// `rows` is the number of rows of the returned content, or `height` if it is larger.
+///
+/// # returns
+/// - the number of rows used by the text: `usize`
+/// - the widest row of the text: `usize`
+/// - the character codes of each row: `Vec<Vec<CharacterCode>>`
+pub(crate) fn wrap_words(
+  text: &str,
+  height: usize,
+  width: usize,
+  rows: usize,
+) -> (usize, usize, Vec<Vec<CharacterCode>>) {
+  let (content_height, content_widest_width, content, _) = wrap_words_truncated(text, height, width, rows);
+  (content_height, content_widest_width, content)
+}
+
+/// same as [`wrap_words`], and also returns whether any of the text was dropped because it did not fit
+/// in `height` rows
+pub(crate) fn wrap_words_truncated(
+  text: &str,
+  height: usize,
+  width: usize,
+  rows: usize,
+) -> (usize, usize, Vec<Vec<CharacterCode>>, bool) {
+  let mut truncated = false;
+  let mut text_mapping: Vec<Vec<CharacterCode>> = vec![Vec::new(); rows.max(height)];
+
+  if text.is_empty() {
+    (0..height).for_each(|i| (0..width).for_each(|_| text_mapping[i].push(CharacterCode::Blank)));
+    return (height, width, text_mapping, false);
+  }
+
+  let mut remove_space = true;
+  let mut text: String = text
+    .chars()
+    .rev()
+    .filter(|c| {
+      if remove_space && *c == ' ' {
+        return false;
+      }
+
+      remove_space = *c == '\n';
+      true
+    })
+    .collect();
+  text = text.chars().rev().collect();
+  tracing::trace!("text: {:?}", text);
+
+  let mut words = text.split_inclusive('\n').flat_map(|s| s.split(' ')).peekable();
+  tracing::trace!("words: {:?}", words.clone().collect::<Vec<_>>());
+
+  let mut row: usize = 0;
+  let mut col: usize = 0;
+  while let Some(word) = words.next() {
+    let next_word = words.peek();
+    tracing::trace!("word: {word}; next_word: {:?}; col: {col}; row: {row}", next_word);
+
+    // a word that would exactly fill the rest of the row still needs a space before it
+    let needs_space = col > 0 && text_mapping.get(row).and_then(|row| row.last()) != Some(&CharacterCode::Blank);
+    if word.len() + usize::from(needs_space) > width.saturating_sub(col)
+      && word.len() < width
+      && word.chars().next().unwrap_or(' ') != '\n'
+    {
+      col = 0;
+      row += 1;
+    }
+
+    let mut ended_on_newline = false;
+    for char in word.chars().map(CharacterCode::from_char_lossy) {
+      tracing::trace!("char: {char}; col: {col}; row: {row}");
+      if col >= width {
+        col = 0;
+        row += 1;
+
+        if char == CharacterCode::Newline {
+          ended_on_newline = true;
+          continue;
+        }
+      }
+
+      if char == CharacterCode::Newline {
+        col = 0;
+        row += 1;
+        ended_on_newline = true;
+        continue;
+      }
+
+      if row >= height {
+        truncated = true;
+        break;
+      }
+
+      text_mapping[row].push(char);
+      col += 1;
+    }
+
+    if let Some(next_word) = next_word {
+      if row < height && col < width && next_word.len() < width - col && !ended_on_newline {
+        text_mapping[row].push(CharacterCode::Blank);
+        col += 1;
+      }
+    }
+  }
+
+  let text_widest_width = text_mapping.iter().map(|row| row.len()).max().unwrap_or(0);
+
+  (row + 1, text_widest_width, text_mapping, truncated)
+}
+
+/// wraps text into as many rows of character codes as it needs, `width` cells wide, with the same word
+/// wrapping as [`wrap_words`]. blank lines are kept as empty rows.
+pub(crate) fn wrap_all(text: &str, width: usize) -> Vec<Vec<CharacterCode>> {
+  // every character adds at most one row
+  let max_rows = text.chars().count() + 1;
+  let (height, _, mut rows) = wrap_words(text, max_rows, width, max_rows);
+  rows.truncate(height);
+  rows
+}
+
+/// splits text into rows of character codes on newlines, cutting off each line at `width` and
+/// dropping lines past `height`. returns the same tuple as [`wrap_words`].
+fn cut_lines(text: &str, height: usize, width: usize) -> (usize, usize, Vec<Vec<CharacterCode>>) {
+  let rows = text
+    .lines()
+    .take(height)
+    .map(|line| {
+      line
+        .trim_end()
+        .chars()
+        .take(width)
+        .map(CharacterCode::from_char_lossy)
+        .collect::<Vec<_>>()
+    })
+    .collect::<Vec<_>>();
+  let widest_width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+  (rows.len(), widest_width, rows)
+}
+
+/// positions rows of content inside a `height`x`width` area according to `justify` and `align`,
+/// calling `put` with the row and column within the area and the character code of every cell.
+/// empty rows of content, such as blank lines, are skipped the same as in VBML unless `keep_blank_lines` is
+/// set, which leaves their row of the area untouched instead. content that is larger than the area starts
+/// at its top left and runs past it.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn place_rows(
+  content: &[Vec<CharacterCode>],
+  keep_blank_lines: bool,
+  content_height: usize,
+  content_widest_width: usize,
+  height: usize,
+  width: usize,
+  justify: Option<&Justify>,
+  align: Option<&Align>,
+  mut put: impl FnMut(usize, usize, CharacterCode),
+) {
+  // indexed within the area
+  let mut starting_row = 0;
+  match align {
+    Some(Align::Center) => starting_row = (height.saturating_sub(content_height) as f64 / 2.0).floor() as usize,
+    Some(Align::Bottom) => starting_row = height.saturating_sub(content_height),
+    Some(Align::Justified) => starting_row = (height.saturating_sub(content_height) as f64 / 2.0).ceil() as usize,
+    _ => {}
+  }
+
+  let rows = content.iter().filter(|row| keep_blank_lines || !row.is_empty());
+  for (row_offset, content_row) in rows.enumerate() {
+    // indexed within the area
+    let mut starting_col = 0;
+
+    match justify {
+      Some(Justify::Center) => starting_col = (width.saturating_sub(content_row.len()) as f64 / 2.0) as usize,
+      Some(Justify::Right) => starting_col = width.saturating_sub(content_row.len()),
+      Some(Justify::Justified) => starting_col = (width.saturating_sub(content_widest_width) as f64 / 2.0) as usize,
+      _ => {}
+    }
+
+    for (col_offset, content_col) in content_row.iter().enumerate() {
+      put(starting_row + row_offset, starting_col + col_offset, *content_col);
+    }
+  }
+}
@@ -0,0 +1,192 @@
+use std::ops::Range;
+
+use super::{canvas::cell_code, layout::wrap_all, transliterate, BoardData, CharacterCode};
+
+/// the direction content moves in a [`Marquee`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScrollDirection {
+  /// the content moves right to left, with each line of the content on its own row
+  #[default]
+  Horizontal,
+  /// the content moves bottom to top, with the content wrapped to the width of the board
+  Vertical,
+}
+
+/// options for scrolling content across a board with [`BoardData::marquee`]
+///
+/// the defaults scroll horizontally across every row, one cell per frame, starting and ending with the
+/// content off the board.
+#[derive(Debug, Clone)]
+pub struct Marquee {
+  /// the direction the content moves
+  pub direction: ScrollDirection,
+  /// the rows the content scrolls through. rows outside of the range are left untouched, and the range
+  /// is clipped to the board
+  pub rows: Range<usize>,
+  /// the number of cells the content moves between frames. a step of 0 is treated as 1
+  pub step: usize,
+  /// the number of blank cells (or rows when scrolling vertically) before and after the content. if
+  /// `None`, the padding is the size of the scrolling area, so the content starts and ends off the board
+  pub padding: Option<usize>,
+}
+
+impl Default for Marquee {
+  fn default() -> Self {
+    Marquee {
+      direction: ScrollDirection::Horizontal,
+      rows: 0..usize::MAX,
+      step: 1,
+      padding: None,
+    }
+  }
+}
+
+impl Marquee {
+  /// options for scrolling vertically
+  pub fn vertical() -> Self {
+    Marquee {
+      direction: ScrollDirection::Vertical,
+      ..Default::default()
+    }
+  }
+
+  /// sets the direction the content moves
+  pub fn direction(mut self, direction: ScrollDirection) -> Self {
+    self.direction = direction;
+    self
+  }
+
+  /// sets the rows the content scrolls through
+  pub fn rows(mut self, rows: Range<usize>) -> Self {
+    self.rows = rows;
+    self
+  }
+
+  /// sets the number of cells the content moves between frames
+  pub fn step(mut self, step: usize) -> Self {
+    self.step = step;
+    self
+  }
+
+  /// sets the number of blank cells before and after the content
+  pub fn padding(mut self, padding: usize) -> Self {
+    self.padding = Some(padding);
+    self
+  }
+
+  /// the offsets of the frames for content of `len` cells scrolling through a window of `window` cells,
+  /// after padding the content on both ends. the last frame always shows the end of the padded content
+  fn offsets(&self, len: usize, window: usize) -> (usize, Offsets) {
+    // padding past the window only adds blank frames, so it is clamped to the window
+    let padding = self.padding.unwrap_or(window).min(window);
+    let last = len.saturating_add(padding * 2).saturating_sub(window);
+
+    let offsets = Offsets {
+      next: Some(0),
+      last,
+      step: self.step.max(1),
+    };
+
+    (padding, offsets)
+  }
+}
+
+/// the offsets of the frames of a [`Marquee`], computed as the frames are generated
+struct Offsets {
+  next: Option<usize>,
+  last: usize,
+  step: usize,
+}
+
+impl Iterator for Offsets {
+  type Item = usize;
+
+  fn next(&mut self) -> Option<usize> {
+    let offset = self.next?;
+    self.next = (offset < self.last).then(|| offset.saturating_add(self.step).min(self.last));
+    Some(offset)
+  }
+}
+
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// generates the frames of `text` scrolling across this board. see [`Marquee`]
+  ///
+  /// the text is converted with [`super::transliterate()`]. when scrolling horizontally, each line of the
+  /// text scrolls on its own row of [`Marquee::rows`], and lines past the last row are dropped. when
+  /// scrolling vertically, the text is wrapped to the width of the board with the same word wrapping as
+  /// [`BoardData::from_text`].
+  ///
+  /// every frame is a copy of this board with only the rows of the marquee replaced, so static parts such
+  /// as a title row stay the same in every frame. frames are generated as the iterator is advanced.
+  ///
+  /// # example
+  /// ```
+  /// let board = FlagshipBoard::centered("breaking news");
+  /// for frame in board.marquee("a very long headline that does not fit on one row", &Marquee::default().rows(4..5)) {
+  ///   client.write_board(frame).await?;
+  /// }
+  /// ```
+  pub fn marquee(&self, text: &str, marquee: &Marquee) -> impl Iterator<Item = Self> + '_ {
+    let text = transliterate(text).text;
+    let lines = match marquee.direction {
+      ScrollDirection::Horizontal => text
+        .lines()
+        .map(|line| line.chars().map(CharacterCode::from_char_lossy).collect())
+        .collect(),
+      ScrollDirection::Vertical => wrap_all(&text, COLS),
+    };
+
+    self.scroll_lines(lines, marquee)
+  }
+
+  /// generates the frames of a row of codes scrolling across this board. when scrolling vertically, the
+  /// codes are split into rows the width of the board. see [`BoardData::marquee`]
+  pub fn marquee_codes(&self, codes: &[CharacterCode], marquee: &Marquee) -> impl Iterator<Item = Self> + '_ {
+    let lines = match marquee.direction {
+      ScrollDirection::Horizontal => vec![codes.to_vec()],
+      ScrollDirection::Vertical => codes.chunks(COLS.max(1)).map(<[_]>::to_vec).collect(),
+    };
+
+    self.scroll_lines(lines, marquee)
+  }
+
+  /// generates the frames of lines of codes scrolling through the rows of the marquee
+  fn scroll_lines(&self, lines: Vec<Vec<CharacterCode>>, marquee: &Marquee) -> impl Iterator<Item = Self> + '_ {
+    let rows = marquee.rows.start.min(ROWS)..marquee.rows.end.min(ROWS);
+    let direction = marquee.direction;
+
+    let (padding, offsets) = match direction {
+      // without any rows to scroll through, the only frame is the board itself
+      _ if rows.is_empty() => marquee.offsets(0, 0),
+      ScrollDirection::Horizontal => {
+        let len = lines.iter().take(rows.len()).map(Vec::len).max().unwrap_or(0);
+        marquee.offsets(len, COLS)
+      }
+      ScrollDirection::Vertical => marquee.offsets(lines.len(), rows.len()),
+    };
+
+    let code_at = move |line: usize, col: usize| -> u8 {
+      lines
+        .get(line)
+        .and_then(|line| line.get(col))
+        .map_or(0, |code| cell_code(*code))
+    };
+
+    offsets.map(move |offset| {
+      let mut frame = self.clone();
+      for (window_row, row) in rows.clone().enumerate() {
+        for (col, code) in frame.0[row].iter_mut().enumerate() {
+          *code = match direction {
+            ScrollDirection::Horizontal => (offset + col)
+              .checked_sub(padding)
+              .map_or(0, |content_col| code_at(window_row, content_col)),
+            ScrollDirection::Vertical => (offset + window_row)
+              .checked_sub(padding)
+              .map_or(0, |line| code_at(line, col)),
+          };
+        }
+      }
+      frame
+    })
+  }
+}
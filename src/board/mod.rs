@@ -12,35 +12,132 @@
 //! the [`BoardData`] struct wraps a raw [`Board`] and provides a more ergonomic interface for working with
 //! it, including display, serialization, and deserialization. printing a [`BoardData`] will display the
 //! board as a grid of characters, with a border around the edges. each character is padded to be two spaces
-//! to be the same width as emoji characters.
+//! to be the same width as emoji characters. for output that lines up in any terminal, use
+//! [`BoardData::to_terminal`], which draws fixed-width cells with 24-bit ANSI colors for the color tiles.
 //!
 //! the [`BoardData`] struct also implements a variety of From and TryFrom traits to make it easier to work
 //! with board data.
+//!
+//! the [`std::fmt::Display`] output of a board cannot be parsed back. for fixtures and other text files,
+//! [`BoardData::to_grid`] and [`BoardData::from_grid`] print and parse a plain text grid with one line per
+//! row and escapes such as `{63}` for the color tiles.
+//!
+//! boards serialize as nested arrays of character codes. for files that people review, the [`BoardRows`]
+//! [`serde_with`] adapter stores a board as an array of row strings, such as `"HELLO {63}"`, and [`CodeName`]
+//! stores a [`CharacterCode`] by name, such as `"red"`.
+//!
+//! boards can be composed with the drawing methods of [`BoardData`], such as [`BoardData::write_text`],
+//! [`BoardData::fill_rect`], [`BoardData::draw_border`], and [`BoardData::blit`]. all of them are bounds-checked.
+//!
+//! metrics can be drawn with dashboard widgets, such as [`BoardData::draw_progress_bar`],
+//! [`BoardData::draw_bar_chart`], and [`BoardData::draw_sparkline`], colored by [`Thresholds`].
+//!
+//! rows of text can be lined up in columns with a [`Table`], such as for a departure board, and
+//! [`BoardData::paginate_table`] splits long tables across boards.
+//!
+//! pixel art and other images can be converted to color tiles with [`BoardData::from_rgb`] and
+//! [`BoardData::from_ppm`], or [`BoardData::from_png`] with the `image` feature, with optional [`Dither`]ing.
+//!
+//! large text for clocks and numbers can be drawn with color tiles in a built-in [`BigFont`] with
+//! [`BoardData::draw_big_text`].
+//!
+//! boards can be moved and reshaped with [`BoardData::shift`], [`BoardData::flip_horizontal`],
+//! [`BoardData::flip_vertical`], [`BoardData::crop`], [`BoardData::embed`], and [`BoardData::pad`].
+//!
+//! text that is too long for a board can be scrolled across it with [`BoardData::marquee`], which generates
+//! the frames of a horizontal or vertical [`Marquee`] while leaving the rest of the board untouched.
+//!
+//! two boards can be compared with [`BoardData::diff`], which lists the changed cells and converts into a
+//! compact [`BoardPatch`] that can be applied back to a board.
+//!
+//! [`BoardData::transition_to`] simulates the split-flap animation between two boards, producing the
+//! intermediate frames along with the flap count and estimated settle time of the [`Transition`].
+//!
+//! text is converted to character codes with [`transliterate()`], which folds accents, maps smart punctuation,
+//! and spells out symbols that are not on the board, and reports every character it replaced or dropped.
+//!
+//! plain text can be laid out on a board with [`BoardData::from_text`] and a [`TextLayout`], which uses the
+//! same word wrapping, justification, and alignment as VBML without building a VBML document.
+//!
+//! text that does not fit on one board can be split across several with [`BoardData::paginate`], which
+//! keeps words together and can add page indicators such as `1/3`.
+//!
+//! the known Vestaboard hardware is described by [`BoardModel`], which provides the dimensions, edition, and
+//! [`BoardCapabilities`] of each model and can be looked up by name. the [`BoardEdition`] decides what blanks and
+//! [`CharacterCode::Filled`] look like, and [`BoardData::convert_edition`] rewrites a board designed for one
+//! edition so it looks the same on the other. [`FlagshipBoard`] and [`NoteBoard`] are ready-made
+//! [`BoardData`] types for each size.
+//!
+//! when the size of a board is only known at runtime, [`DynBoardData`] stores the dimensions alongside
+//! the character codes and converts to and from [`BoardData`].
 
 use std::ops::Deref;
 
-use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use thiserror::Error;
 
+mod adapters;
+mod canvas;
 pub(crate) mod char;
-pub use char::CharacterCode;
-
-lazy_static::lazy_static! {
-  static ref BOARD_REGEX: regex::Regex = Regex::new("[^0-9,]").expect("failed to create regex");
-}
+pub(crate) mod color;
+mod diff;
+mod dynamic;
+mod edition;
+mod font;
+mod image;
+pub(crate) mod layout;
+mod marquee;
+mod model;
+mod paginate;
+mod parse;
+mod table;
+mod terminal;
+mod text;
+mod transform;
+mod transition;
+mod transliterate;
+mod validate;
+mod widget;
+pub use adapters::{BoardRows, CodeName};
+pub use canvas::Rect;
+pub use char::{CharacterCode, CharacterCodeError};
+pub use color::{tile_color, Rgb};
+pub use diff::{BoardDiff, BoardPatch, CellChange, PatchRun};
+pub use dynamic::DynBoardData;
+pub use font::BigFont;
+pub use image::{Dither, ImageError, ImageOptions};
+pub use layout::{Align, Justify, TextLayout};
+pub use marquee::{Marquee, ScrollDirection};
+pub use model::{BoardCapabilities, BoardEdition, BoardModel};
+pub use paginate::{Corner, Pagination};
+pub use table::{Column, Overflow, Table};
+pub use terminal::TerminalOptions;
+pub use transform::EdgeMode;
+pub use transition::{Transition, DEFAULT_FLAP_DURATION, FLAP_ORDER};
+pub use transliterate::{transliterate, Replacement, Transliteration};
+pub use validate::{CodeHandling, InvalidCell};
+pub use widget::{Bar, BarChart, Orientation, ProgressBar, Sparkline, Thresholds, WidgetColor};
 
 /// the number of rows in the flagship Vestaboard
 pub const FLAGSHIP_ROWS: usize = 6;
 /// the number of columns in the flagship Vestaboard
 pub const FLAGSHIP_COLS: usize = 22;
+/// the number of rows in the Vestaboard Note
+pub const NOTE_ROWS: usize = 3;
+/// the number of columns in the Vestaboard Note
+pub const NOTE_COLS: usize = 15;
 
 /// representation of a Vestaboard with character codes
 pub type TextBoard<const ROWS: usize, const COLS: usize> = [[char; COLS]; ROWS];
 /// representation of a Vestaboard with u8 values
 pub type Board<const ROWS: usize = FLAGSHIP_ROWS, const COLS: usize = FLAGSHIP_COLS> = [[u8; COLS]; ROWS];
 
+/// a [`BoardData`] sized for the flagship Vestaboard (6x22)
+pub type FlagshipBoard = BoardData<FLAGSHIP_ROWS, FLAGSHIP_COLS>;
+/// a [`BoardData`] sized for the Vestaboard Note (3x15)
+pub type NoteBoard = BoardData<NOTE_ROWS, NOTE_COLS>;
+
 /// a Vestaboard representation with character codes. defaults to the flagship board size.
 /// wraps a raw [`Board`].
 ///
@@ -63,7 +160,9 @@ impl<const ROWS: usize, const COLS: usize> Default for BoardData<ROWS, COLS> {
 impl<const ROWS: usize, const COLS: usize> From<TextBoard<ROWS, COLS>> for BoardData<ROWS, COLS> {
   /// constructs a new [`BoardData`] from a [`TextBoard`] type
   fn from(value: TextBoard<ROWS, COLS>) -> Self {
-    value.map(|row| row.map(|c| CharacterCode::from(c) as u8)).into()
+    value
+      .map(|row| row.map(|c| CharacterCode::from_char_lossy(c) as u8))
+      .into()
   }
 }
 
@@ -98,13 +197,16 @@ impl<const ROWS: usize, const COLS: usize> PartialEq<Board<ROWS, COLS>> for Boar
 impl<const ROWS: usize, const COLS: usize> std::str::FromStr for BoardData<ROWS, COLS> {
   type Err = BoardError;
 
-  /// attempts to parse a string into a [`BoardData`]. input should be string representation
-  /// of a board, with each cell separated by a comma.
+  /// attempts to parse a string into a [`BoardData`]. input should be the string representation
+  /// of a board as a nested array, with exactly `ROWS` rows of `COLS` character codes. whitespace
+  /// and trailing commas are allowed.
   ///
   /// # errors
-  /// - [`BoardError::TooManyRows`] if there are too many rows in the input
-  /// - [`BoardError::TooManyCols`] if there are too many columns in the input
-  /// - [`BoardError::InvalidChar`] if there is an invalid character in the input
+  /// - [`BoardError::UnexpectedChar`] or [`BoardError::UnexpectedEnd`] if the input is not a nested array
+  /// - [`BoardError::TooManyRows`] or [`BoardError::TooFewRows`] if the input does not have `ROWS` rows
+  /// - [`BoardError::TooManyCols`] or [`BoardError::TooFewCols`] if a row does not have `COLS` columns
+  /// - [`BoardError::InvalidChar`] if a cell is not a number
+  /// - [`BoardError::InvalidCode`] if a cell is a number that is not a character code
   ///
   /// # examples
   /// ```
@@ -112,26 +214,23 @@ impl<const ROWS: usize, const COLS: usize> std::str::FromStr for BoardData<ROWS,
   /// let board: BoardData<ROWS, COLS> = string.parse().unwrap();
   /// ```
   fn from_str(s: &str) -> Result<Self, Self::Err> {
-    let mut board: Board<ROWS, COLS> = [[0; COLS]; ROWS];
-    let s = BOARD_REGEX.replace_all(s, "");
-
-    for (i, val) in s.split(',').enumerate() {
-      let row = i / FLAGSHIP_COLS;
-      let col = i % FLAGSHIP_COLS;
-
-      if row >= FLAGSHIP_ROWS || col >= FLAGSHIP_COLS && val.is_empty() {
-        continue;
-      }
-
-      if row >= FLAGSHIP_ROWS {
-        return Err(BoardError::TooManyRows);
-      }
+    BoardData::parse_with(s, CodeHandling::Strict)
+  }
+}
 
-      if col >= FLAGSHIP_COLS {
-        return Err(BoardError::TooManyCols);
-      }
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// parses a string into a [`BoardData`], choosing how to handle numbers that are not character codes.
+  /// see [`BoardData::from_str`](std::str::FromStr::from_str)
+  ///
+  /// # errors
+  /// - the same as [`BoardData::from_str`](std::str::FromStr::from_str), except that
+  ///   [`BoardError::InvalidCode`] is only returned with [`CodeHandling::Strict`]
+  pub fn parse_with(s: &str, handling: CodeHandling) -> Result<Self, BoardError> {
+    let rows = parse::BoardParser::new(s).parse(Some((ROWS, COLS)), handling)?;
 
-      board[row][col] = val.parse().map_err(|_| BoardError::InvalidChar(val.to_string()))?;
+    let mut board: Board<ROWS, COLS> = [[0; COLS]; ROWS];
+    for (row, values) in board.iter_mut().zip(rows) {
+      row.copy_from_slice(&values);
     }
 
     Ok(BoardData(board))
@@ -147,50 +246,102 @@ impl<const ROWS: usize, const COLS: usize> From<BoardData<ROWS, COLS>> for Board
 
 impl<const ROWS: usize, const COLS: usize> std::fmt::Display for BoardData<ROWS, COLS> {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    writeln!(f, " {}", "-".repeat(COLS * 2))?;
+    fmt_rows(f, COLS, self.0.iter().map(|row| row.as_slice()))
+  }
+}
 
-    for row in self.0.iter() {
-      for (col_idx, col) in row.iter().enumerate() {
-        if col_idx == 0 {
-          write!(f, "|")?;
-        }
+/// writes rows of character codes as a grid of characters with a border around the edges
+pub(crate) fn fmt_rows<'a>(
+  f: &mut std::fmt::Formatter<'_>,
+  cols: usize,
+  rows: impl Iterator<Item = &'a [u8]>,
+) -> std::fmt::Result {
+  writeln!(f, " {}", "-".repeat(cols * 2))?;
 
-        CharacterCode::from(*col).fmt(f)?;
+  for row in rows {
+    for (col_idx, col) in row.iter().enumerate() {
+      if col_idx == 0 {
+        write!(f, "|")?;
+      }
+
+      std::fmt::Display::fmt(&CharacterCode::from_u8_lossy(*col), f)?;
 
-        if col_idx == COLS - 1 {
-          write!(f, "|")?;
-        }
+      if col_idx == cols - 1 {
+        write!(f, "|")?;
       }
-      writeln!(f)?;
     }
+    writeln!(f)?;
+  }
 
-    writeln!(f, " {}", "-".repeat(COLS * 2))?;
+  writeln!(f, " {}", "-".repeat(cols * 2))?;
 
-    Ok(())
-  }
+  Ok(())
 }
 
-/// error type for the Vestaboard board module
+/// error type for the Vestaboard board module. rows and columns are zero-indexed.
 /// - [`BoardError::TooManyRows`] if there are too many rows in the input
-/// - [`BoardError::TooManyCols`] if there are too many columns in the input
+/// - [`BoardError::TooFewRows`] if there are too few rows in the input
+/// - [`BoardError::TooManyCols`] if there are too many columns in a row of the input
+/// - [`BoardError::TooFewCols`] if there are too few columns in a row of the input
 /// - [`BoardError::InvalidChar`] if there is an invalid character in the input
-/// - [`BoardError::Regex`] if there is an error with regex parsing of board data
+/// - [`BoardError::InvalidCode`] if there is a number that is not a character code in the input
+/// - [`BoardError::UnexpectedChar`] if the input is not a nested array
+/// - [`BoardError::UnexpectedEnd`] if the input ends before the board is closed
 /// - [`BoardError::InvalidLength`] if the length of the input is invalid
+/// - [`BoardError::TooLarge`] if a board has more cells than fit in memory
+/// - [`BoardError::DimensionMismatch`] if the dimensions of two boards do not match
+/// - [`BoardError::OutOfBounds`] if a position is outside of the board
+/// - [`BoardError::UnknownModel`] if there is no [`BoardModel`] with the given name
+/// - [`BoardError::InvalidCells`] if cells of a board cannot be displayed
 #[derive(Error, Debug)]
 pub enum BoardError {
-  /// too many rows in the input
-  #[error("too many rows in the input")]
-  TooManyRows,
-  /// too many columns in the input
-  #[error("too many columns in the input")]
-  TooManyCols,
-  /// invalid character in the input, see the wrapped string for the invalid character
-  #[error("invalid character in the input: {0}")]
-  InvalidChar(String),
-  /// regex error (usually while parsing replacement of board data), see the wrapped regex::Error for more details
-  #[error("regex error: {0}")]
-  Regex(#[from] regex::Error),
+  /// too many rows in the input, see `row` for the first extra row
+  #[error("too many rows in the input: found row {row}, expected {expected} rows")]
+  TooManyRows { row: usize, expected: usize },
+  /// too few rows in the input
+  #[error("too few rows in the input: found {found} rows, expected {expected}")]
+  TooFewRows { found: usize, expected: usize },
+  /// too many columns in a row of the input, see `row` and `col` for the first extra column
+  #[error("too many columns in row {row}: found column {col}, expected {expected} columns")]
+  TooManyCols { row: usize, col: usize, expected: usize },
+  /// too few columns in a row of the input
+  #[error("too few columns in row {row}: found {found} columns, expected {expected}")]
+  TooFewCols { row: usize, found: usize, expected: usize },
+  /// invalid character in the input, see `value` for the invalid value
+  #[error("invalid character at ({row}, {col}): {value}")]
+  InvalidChar { row: usize, col: usize, value: String },
+  /// number that is not a character code in the input, see `value` for the invalid code
+  #[error("invalid character code at ({row}, {col}): {value}")]
+  InvalidCode { row: usize, col: usize, value: String },
+  /// the input is not a nested array, see `index` for the byte offset of the unexpected character
+  #[error("unexpected `{found}` at index {index}, expected {expected}")]
+  UnexpectedChar {
+    index: usize,
+    found: char,
+    expected: &'static str,
+  },
+  /// the input ended before the board was closed
+  #[error("unexpected end of input, expected {expected}")]
+  UnexpectedEnd { expected: &'static str },
   /// invalid board length
   #[error("invalid length")]
   InvalidLength,
+  /// a `rows`x`cols` board has more cells than a `usize` can count
+  #[error("a {rows}x{cols} board is too large")]
+  TooLarge { rows: usize, cols: usize },
+  /// the dimensions of the board do not match the expected dimensions, as `(rows, cols)`
+  #[error("expected a {}x{} board, found a {}x{} board", expected.0, expected.1, found.0, found.1)]
+  DimensionMismatch {
+    expected: (usize, usize),
+    found: (usize, usize),
+  },
+  /// the position is outside of the board
+  #[error("position ({row}, {col}) is out of bounds")]
+  OutOfBounds { row: usize, col: usize },
+  /// there is no [`BoardModel`] with the name, see the wrapped string for the name
+  #[error("unknown board model: {0}")]
+  UnknownModel(String),
+  /// cells of the board cannot be displayed, see the wrapped [`InvalidCell`]s for every invalid cell
+  #[error("{} invalid cells: {}", .0.len(), .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+  InvalidCells(Vec<InvalidCell>),
 }
@@ -0,0 +1,188 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+  BoardError, CharacterCode, DynBoardData, FlagshipBoard, NoteBoard, FLAGSHIP_COLS, FLAGSHIP_ROWS, NOTE_COLS, NOTE_ROWS,
+};
+
+/// the color edition of a Vestaboard. the edition determines the color of the blank bits
+/// and the housing of the board.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BoardEdition {
+  /// black bits with white characters (the original edition)
+  #[default]
+  Black,
+  /// white bits with black characters
+  White,
+}
+
+/// the color tiles that can be told apart from a blank on the black edition
+const BLACK_EDITION_COLORS: [CharacterCode; 8] = [
+  CharacterCode::Red,
+  CharacterCode::Orange,
+  CharacterCode::Yellow,
+  CharacterCode::Green,
+  CharacterCode::Blue,
+  CharacterCode::Violet,
+  CharacterCode::White,
+  CharacterCode::Filled,
+];
+
+/// the color tiles that can be told apart from a blank on the white edition
+const WHITE_EDITION_COLORS: [CharacterCode; 8] = [
+  CharacterCode::Red,
+  CharacterCode::Orange,
+  CharacterCode::Yellow,
+  CharacterCode::Green,
+  CharacterCode::Blue,
+  CharacterCode::Violet,
+  CharacterCode::Black,
+  CharacterCode::Filled,
+];
+
+/// the features of a [`BoardModel`], see [`BoardModel::capabilities`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoardCapabilities {
+  /// the color edition of the model, which decides what blanks and [`CharacterCode::Filled`] look like
+  pub edition: BoardEdition,
+  /// the color tile that looks the same as a blank cell, see [`BoardEdition::blank`]
+  pub blank: CharacterCode,
+  /// the color tiles that can be told apart from a blank cell. every model can display all of the color
+  /// codes (63-71), but the tile with the color of the blank bits is left out since it looks like a blank
+  pub colors: &'static [CharacterCode],
+}
+
+impl BoardCapabilities {
+  /// whether `code` is a color tile that can be told apart from a blank cell on the model
+  pub fn shows_color(&self, code: CharacterCode) -> bool {
+    self.colors.contains(&code)
+  }
+}
+
+/// the Vestaboard hardware models, with their dimensions, edition, and capabilities.
+///
+/// every model is listed in [`BoardModel::ALL`], and a model can be looked up by name with
+/// [`str::parse`]. names are kebab-case, such as `flagship`, `flagship-white`, `note`, and `note-white`.
+///
+/// the dimensions of a model are also available as consts for use as `ROWS` and `COLS`, see
+/// [`FLAGSHIP_ROWS`], [`FLAGSHIP_COLS`], [`NOTE_ROWS`], and [`NOTE_COLS`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BoardModel {
+  /// the flagship 6x22 Vestaboard, black edition
+  #[default]
+  Flagship,
+  /// the flagship 6x22 Vestaboard, white edition
+  FlagshipWhite,
+  /// the 3x15 Vestaboard Note, black edition
+  Note,
+  /// the 3x15 Vestaboard Note, white edition
+  NoteWhite,
+}
+
+impl BoardModel {
+  /// every known Vestaboard model
+  pub const ALL: [BoardModel; 4] = [
+    BoardModel::Flagship,
+    BoardModel::FlagshipWhite,
+    BoardModel::Note,
+    BoardModel::NoteWhite,
+  ];
+
+  /// the name of the model, as accepted by [`str::parse`]
+  pub fn name(&self) -> &'static str {
+    match self {
+      BoardModel::Flagship => "flagship",
+      BoardModel::FlagshipWhite => "flagship-white",
+      BoardModel::Note => "note",
+      BoardModel::NoteWhite => "note-white",
+    }
+  }
+
+  /// the number of rows of the model
+  pub fn rows(&self) -> usize {
+    match self {
+      BoardModel::Flagship | BoardModel::FlagshipWhite => FLAGSHIP_ROWS,
+      BoardModel::Note | BoardModel::NoteWhite => NOTE_ROWS,
+    }
+  }
+
+  /// the number of columns of the model
+  pub fn cols(&self) -> usize {
+    match self {
+      BoardModel::Flagship | BoardModel::FlagshipWhite => FLAGSHIP_COLS,
+      BoardModel::Note | BoardModel::NoteWhite => NOTE_COLS,
+    }
+  }
+
+  /// the dimensions of the model as `(rows, cols)`
+  pub fn dimensions(&self) -> (usize, usize) {
+    (self.rows(), self.cols())
+  }
+
+  /// the color edition of the model
+  pub fn edition(&self) -> BoardEdition {
+    match self {
+      BoardModel::Flagship | BoardModel::Note => BoardEdition::Black,
+      BoardModel::FlagshipWhite | BoardModel::NoteWhite => BoardEdition::White,
+    }
+  }
+
+  /// the features of the model, which depend on its edition
+  pub fn capabilities(&self) -> BoardCapabilities {
+    let edition = self.edition();
+    let colors: &'static [CharacterCode] = match edition {
+      BoardEdition::Black => &BLACK_EDITION_COLORS,
+      BoardEdition::White => &WHITE_EDITION_COLORS,
+    };
+
+    BoardCapabilities {
+      edition,
+      blank: edition.blank(),
+      colors,
+    }
+  }
+
+  /// the models with the given dimensions, in the order of [`BoardModel::ALL`]
+  pub fn with_dimensions(rows: usize, cols: usize) -> impl Iterator<Item = BoardModel> {
+    BoardModel::ALL
+      .into_iter()
+      .filter(move |model| model.dimensions() == (rows, cols))
+  }
+
+  /// creates a blank [`DynBoardData`] with the dimensions of the model
+  pub fn blank_board(&self) -> DynBoardData {
+    // built from the fixed size boards, so there is no size that could overflow
+    match self {
+      BoardModel::Flagship | BoardModel::FlagshipWhite => FlagshipBoard::default().into(),
+      BoardModel::Note | BoardModel::NoteWhite => NoteBoard::default().into(),
+    }
+  }
+}
+
+impl std::str::FromStr for BoardModel {
+  type Err = BoardError;
+
+  /// looks up a model by name. names are case-insensitive, and `-black` may be appended to
+  /// the name of a black edition model.
+  ///
+  /// # errors
+  /// - [`BoardError::UnknownModel`] if there is no model with the name
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let name = s.trim().to_ascii_lowercase();
+
+    BoardModel::ALL
+      .into_iter()
+      .find(|model| {
+        model.name() == name
+          || (model.edition() == BoardEdition::Black && name.strip_suffix("-black") == Some(model.name()))
+      })
+      .ok_or_else(|| BoardError::UnknownModel(s.to_string()))
+  }
+}
+
+impl std::fmt::Display for BoardModel {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(self.name())
+  }
+}
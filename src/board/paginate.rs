@@ -0,0 +1,138 @@
+use super::{
+  layout::{place_rows, wrap_all},
+  transliterate, Align, BoardData, Justify,
+};
+
+/// a corner of a board
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Corner {
+  TopLeft,
+  TopRight,
+  BottomLeft,
+  #[default]
+  BottomRight,
+}
+
+/// options for splitting text across boards with [`BoardData::paginate`]
+///
+/// the defaults are left justified and top aligned, without page indicators.
+#[derive(Debug, Clone)]
+pub struct Pagination {
+  /// the horizontal justification of the text on every page
+  pub justify: Justify,
+  /// the vertical alignment of the text on every page
+  pub align: Align,
+  /// the corner to show a page indicator such as `1/3` in, if any. the row of the indicator is kept free
+  /// of text on every page
+  pub indicator: Option<Corner>,
+}
+
+impl Default for Pagination {
+  fn default() -> Self {
+    Pagination {
+      justify: Justify::Left,
+      align: Align::Top,
+      indicator: None,
+    }
+  }
+}
+
+impl Pagination {
+  /// sets the horizontal justification of the text on every page
+  pub fn justify(mut self, justify: Justify) -> Self {
+    self.justify = justify;
+    self
+  }
+
+  /// sets the vertical alignment of the text on every page
+  pub fn align(mut self, align: Align) -> Self {
+    self.align = align;
+    self
+  }
+
+  /// shows a page indicator such as `1/3` in a corner of every page
+  pub fn indicator(mut self, corner: Corner) -> Self {
+    self.indicator = Some(corner);
+    self
+  }
+}
+
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// splits text of any length across as many boards as it needs, using the same word wrapping as
+  /// [`BoardData::from_text`]. pages break between rows, so words are never split across pages unless
+  /// they are too long for a single row. blank lines are kept, except at the top of a page.
+  ///
+  /// the text is converted with [`super::transliterate()`]. there is always at least one page.
+  ///
+  /// # example
+  /// ```
+  /// let pagination = Pagination::default().indicator(Corner::BottomRight);
+  /// let pages: Vec<FlagshipBoard> = BoardData::paginate(long_text, &pagination);
+  /// for page in pages.iter().cycle() {
+  ///   client.write_board(page.clone()).await?;
+  /// }
+  /// ```
+  pub fn paginate(text: &str, pagination: &Pagination) -> Vec<Self> {
+    // a one row board has no room for an indicator
+    let indicator = pagination.indicator.filter(|_| ROWS > 1);
+    let height = if indicator.is_some() { ROWS - 1 } else { ROWS };
+    if height == 0 || COLS == 0 {
+      return vec![Self::default()];
+    }
+
+    let mut pages = Vec::new();
+    let mut rows = wrap_all(&transliterate(text).text, COLS).into_iter().peekable();
+    loop {
+      while rows.next_if(Vec::is_empty).is_some() {}
+      let page = rows.by_ref().take(height).collect::<Vec<_>>();
+      if page.is_empty() && !pages.is_empty() {
+        break;
+      }
+      pages.push(page);
+    }
+
+    let count = pages.len();
+    let top = match indicator {
+      Some(Corner::TopLeft | Corner::TopRight) => 1,
+      _ => 0,
+    };
+
+    pages
+      .iter()
+      .enumerate()
+      .map(|(index, page)| {
+        let mut board = Self::default();
+        let widest = page.iter().map(Vec::len).max().unwrap_or(0);
+        place_rows(
+          page,
+          true,
+          page.len(),
+          widest,
+          height,
+          COLS,
+          Some(&pagination.justify),
+          Some(&pagination.align),
+          |row, col, code| {
+            if let Some(cell) = board.0.get_mut(top + row).and_then(|row| row.get_mut(col)) {
+              *cell = code.into();
+            }
+          },
+        );
+
+        if let Some(corner) = indicator {
+          let label = transliterate(&format!("{}/{count}", index + 1)).codes();
+          let label = &label[..label.len().min(COLS)];
+          let row = if top == 1 { 0 } else { ROWS - 1 };
+          let col = match corner {
+            Corner::TopLeft | Corner::BottomLeft => 0,
+            Corner::TopRight | Corner::BottomRight => COLS - label.len(),
+          };
+          // the label is clipped to the board, so it always fits
+          let _ = board.write_codes(row, col, label);
+        }
+
+        board
+      })
+      .collect()
+  }
+}
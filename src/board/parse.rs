@@ -0,0 +1,211 @@
+use super::{BoardError, CharacterCode, CodeHandling};
+
+/// a parser for the string representation of a board, such as the `layout` returned by the read/write api.
+///
+/// the input must be a nested array of character codes (`[[0,1,...],[...],...]`). whitespace and trailing
+/// commas are allowed anywhere, as are trailing semicolons after the board.
+pub(crate) struct BoardParser<'a> {
+  input: &'a str,
+  index: usize,
+  handling: CodeHandling,
+}
+
+impl<'a> BoardParser<'a> {
+  pub(crate) fn new(input: &'a str) -> Self {
+    BoardParser {
+      input,
+      index: 0,
+      handling: CodeHandling::Strict,
+    }
+  }
+
+  /// parses the input into rows of character codes.
+  ///
+  /// if `dimensions` is provided as `(rows, cols)`, the board must have exactly that many rows and columns.
+  /// otherwise every row must be the same length as the first row.
+  ///
+  /// with [`CodeHandling::Lossy`], numbers that are not character codes are parsed as blanks.
+  pub(crate) fn parse(
+    mut self,
+    dimensions: Option<(usize, usize)>,
+    handling: CodeHandling,
+  ) -> Result<Vec<Vec<u8>>, BoardError> {
+    self.handling = handling;
+    let mut rows: Vec<Vec<u8>> = Vec::with_capacity(dimensions.map_or(0, |(rows, _)| rows));
+
+    self.expect('[', "`[` to open the board")?;
+    loop {
+      match self.peek() {
+        Some(']') => break,
+        Some('[') => {}
+        Some(found) => return Err(self.unexpected(found, "`[` to open a row or `]` to close the board")),
+        None => {
+          return Err(BoardError::UnexpectedEnd {
+            expected: "`]` to close the board",
+          })
+        }
+      }
+
+      let row = rows.len();
+      if let Some((expected, _)) = dimensions {
+        if row >= expected {
+          return Err(BoardError::TooManyRows { row, expected });
+        }
+      }
+
+      let expected_cols = dimensions
+        .map(|(_, cols)| cols)
+        .or(rows.first().map(|first| first.len()));
+      rows.push(self.row(row, expected_cols)?);
+
+      if !self.separator(']', "`,` or `]` to close the board")? {
+        break;
+      }
+    }
+    self.expect(']', "`]` to close the board")?;
+
+    self.skip_whitespace();
+    while self.peek() == Some(';') {
+      self.index += 1;
+      self.skip_whitespace();
+    }
+    if let Some(found) = self.peek() {
+      return Err(self.unexpected(found, "the end of the input"));
+    }
+
+    if let Some((expected, _)) = dimensions {
+      if rows.len() < expected {
+        return Err(BoardError::TooFewRows {
+          found: rows.len(),
+          expected,
+        });
+      }
+    }
+
+    Ok(rows)
+  }
+
+  /// parses a single row, validating its length against `expected_cols` if provided
+  fn row(&mut self, row: usize, expected_cols: Option<usize>) -> Result<Vec<u8>, BoardError> {
+    let mut codes = Vec::with_capacity(expected_cols.unwrap_or(0));
+
+    self.expect('[', "`[` to open a row")?;
+    while self.peek().is_some_and(|c| c != ']') {
+      let col = codes.len();
+      let value = self.value();
+
+      if value.is_empty() {
+        let found = self.peek().unwrap_or_default();
+        return Err(self.unexpected(found, "a character code"));
+      }
+
+      if let Some(expected) = expected_cols {
+        if col >= expected {
+          return Err(BoardError::TooManyCols { row, col, expected });
+        }
+      }
+
+      codes.push(parse_code(value, self.handling).ok_or_else(|| invalid_value(row, col, value))?);
+
+      if !self.separator(']', "`,` or `]` to close the row")? {
+        break;
+      }
+    }
+    self.expect(']', "`]` to close the row")?;
+
+    if let Some(expected) = expected_cols {
+      if codes.len() < expected {
+        return Err(BoardError::TooFewCols {
+          row,
+          found: codes.len(),
+          expected,
+        });
+      }
+    }
+
+    Ok(codes)
+  }
+
+  /// consumes a `,` if one is next. returns whether another item may follow, which is
+  /// `false` if `close` is next instead.
+  fn separator(&mut self, close: char, expected: &'static str) -> Result<bool, BoardError> {
+    match self.peek() {
+      Some(',') => {
+        self.index += 1;
+        Ok(self.peek() != Some(close))
+      }
+      Some(c) if c == close => Ok(false),
+      Some(found) => Err(self.unexpected(found, expected)),
+      None => Err(BoardError::UnexpectedEnd { expected }),
+    }
+  }
+
+  /// consumes the next value, which runs until whitespace, a separator, or a bracket
+  fn value(&mut self) -> &'a str {
+    let rest = &self.input[self.index..];
+    let len = rest
+      .find(|c: char| c.is_whitespace() || matches!(c, ',' | '[' | ']'))
+      .unwrap_or(rest.len());
+
+    self.index += len;
+    &rest[..len]
+  }
+
+  fn expect(&mut self, expected_char: char, expected: &'static str) -> Result<(), BoardError> {
+    match self.peek() {
+      Some(c) if c == expected_char => {
+        self.index += c.len_utf8();
+        Ok(())
+      }
+      Some(found) => Err(self.unexpected(found, expected)),
+      None => Err(BoardError::UnexpectedEnd { expected }),
+    }
+  }
+
+  /// skips whitespace and returns the next character without consuming it
+  fn peek(&mut self) -> Option<char> {
+    self.skip_whitespace();
+    self.input[self.index..].chars().next()
+  }
+
+  fn skip_whitespace(&mut self) {
+    let rest = &self.input[self.index..];
+    self.index += rest.len() - rest.trim_start().len();
+  }
+
+  fn unexpected(&self, found: char, expected: &'static str) -> BoardError {
+    BoardError::UnexpectedChar {
+      index: self.index,
+      found,
+      expected,
+    }
+  }
+}
+
+/// parses a character code, returning `None` if it is not a number, or with [`CodeHandling::Strict`],
+/// if it is not a code that can be displayed in a cell
+fn parse_code(value: &str, handling: CodeHandling) -> Option<u8> {
+  if !value.bytes().all(|b| b.is_ascii_digit()) {
+    return None;
+  }
+
+  let code = value
+    .parse::<u8>()
+    .ok()
+    .filter(|code| CharacterCode::is_cell_code(*code));
+  match handling {
+    CodeHandling::Strict => code,
+    CodeHandling::Lossy => Some(code.unwrap_or(CharacterCode::Blank.into())),
+  }
+}
+
+/// the error for a value that is not a valid character code
+fn invalid_value(row: usize, col: usize, value: &str) -> BoardError {
+  let value = value.to_string();
+
+  if value.bytes().all(|b| b.is_ascii_digit()) {
+    BoardError::InvalidCode { row, col, value }
+  } else {
+    BoardError::InvalidChar { row, col, value }
+  }
+}
@@ -0,0 +1,203 @@
+use super::{transliterate, BoardData, BoardError, CharacterCode, Justify};
+
+/// what happens to the text of a cell that is wider than its [`Column`]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Overflow {
+  /// the text is cut off at the edge of the column
+  #[default]
+  Truncate,
+  /// the text is cut off and its last cell is replaced with a `.`
+  Ellipsis,
+}
+
+/// a column of a [`Table`]
+#[derive(Debug, Clone)]
+pub struct Column {
+  /// the number of cells in the column
+  pub width: usize,
+  /// the position of the text in the column. [`Justify::Justified`] is the same as [`Justify::Left`]
+  pub justify: Justify,
+  /// what happens to text that is wider than the column
+  pub overflow: Overflow,
+}
+
+impl Column {
+  /// creates a left justified column that truncates its text
+  pub fn new(width: usize) -> Self {
+    Column {
+      width,
+      justify: Justify::Left,
+      overflow: Overflow::Truncate,
+    }
+  }
+
+  /// sets the position of the text in the column
+  pub fn justify(mut self, justify: Justify) -> Self {
+    self.justify = justify;
+    self
+  }
+
+  /// sets what happens to text that is wider than the column
+  pub fn overflow(mut self, overflow: Overflow) -> Self {
+    self.overflow = overflow;
+    self
+  }
+
+  /// lays out the text of a cell in the column
+  fn cell(&self, text: &str) -> Vec<CharacterCode> {
+    let mut codes = transliterate(text).codes();
+    if codes.len() > self.width {
+      codes.truncate(self.width);
+      if let (Overflow::Ellipsis, Some(last)) = (self.overflow, codes.last_mut()) {
+        *last = CharacterCode::Period;
+      }
+    }
+
+    let space = self.width - codes.len();
+    let before = match self.justify {
+      Justify::Left | Justify::Justified => 0,
+      Justify::Right => space,
+      Justify::Center => space / 2,
+    };
+
+    let mut cell = vec![CharacterCode::Blank; before];
+    cell.extend(codes);
+    cell.resize(self.width, CharacterCode::Blank);
+    cell
+  }
+}
+
+/// a table of text for [`BoardData::from_table`], such as a departure board or a leaderboard
+///
+/// by default, columns are separated by a blank column.
+#[derive(Debug, Clone)]
+pub struct Table {
+  /// the columns, from left to right
+  pub columns: Vec<Column>,
+  /// a row shown at the top of every page
+  pub header: Option<Vec<String>>,
+  /// the rows of cells. cells past the last column are ignored, and missing cells are blank
+  pub rows: Vec<Vec<String>>,
+  /// the code of a one cell wide column between every two columns, or `None` for no separator
+  pub separator: Option<CharacterCode>,
+}
+
+impl Table {
+  /// creates an empty table with the columns
+  pub fn new(columns: Vec<Column>) -> Self {
+    Table {
+      columns,
+      header: None,
+      rows: Vec::new(),
+      separator: Some(CharacterCode::Blank),
+    }
+  }
+
+  /// sets the row shown at the top of every page
+  pub fn header<S: Into<String>>(mut self, header: impl IntoIterator<Item = S>) -> Self {
+    self.header = Some(header.into_iter().map(Into::into).collect());
+    self
+  }
+
+  /// adds a row of cells
+  pub fn row<S: Into<String>>(mut self, row: impl IntoIterator<Item = S>) -> Self {
+    self.rows.push(row.into_iter().map(Into::into).collect());
+    self
+  }
+
+  /// sets the code of the column between every two columns, or `None` for no separator
+  pub fn separator(mut self, separator: Option<CharacterCode>) -> Self {
+    self.separator = separator;
+    self
+  }
+
+  /// the number of cells the table is wide, including separators
+  pub fn width(&self) -> usize {
+    let separators = match self.separator {
+      Some(_) => self.columns.len().saturating_sub(1),
+      None => 0,
+    };
+
+    self.columns.iter().map(|column| column.width).sum::<usize>() + separators
+  }
+
+  /// lays out a row of the table
+  fn line(&self, cells: &[String]) -> Vec<CharacterCode> {
+    let mut line = Vec::with_capacity(self.width());
+    for (index, column) in self.columns.iter().enumerate() {
+      if let (Some(separator), true) = (self.separator, index > 0) {
+        line.push(separator);
+      }
+      line.extend(column.cell(cells.get(index).map_or("", String::as_str)));
+    }
+
+    line
+  }
+}
+
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// lays out a [`Table`] on a new board, starting at the top left. rows that do not fit on the board are
+  /// dropped, see [`BoardData::paginate_table`] to show every row.
+  ///
+  /// # example
+  /// ```
+  /// let table = Table::new(vec![
+  ///   Column::new(5),
+  ///   Column::new(10).overflow(Overflow::Ellipsis),
+  ///   Column::new(5).justify(Justify::Right),
+  /// ])
+  /// .header(["time", "to", "gate"])
+  /// .row(["09:15", "new york", "b12"])
+  /// .row(["09:40", "san francisco", "a3"]);
+  ///
+  /// let board: FlagshipBoard = BoardData::from_table(&table)?;
+  /// ```
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the table is wider than the board
+  pub fn from_table(table: &Table) -> Result<Self, BoardError> {
+    let mut pages = Self::table_pages(table, false)?;
+    Ok(pages.swap_remove(0))
+  }
+
+  /// lays out a [`Table`] on as many boards as it needs, with the header at the top of every board.
+  /// there is always at least one page.
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the table is wider than the board, or the board has no room for
+  ///   rows below the header
+  pub fn paginate_table(table: &Table) -> Result<Vec<Self>, BoardError> {
+    Self::table_pages(table, true)
+  }
+
+  /// lays out the pages of a table, or only the first page if `all` is `false`
+  fn table_pages(table: &Table, all: bool) -> Result<Vec<Self>, BoardError> {
+    let width = table.width();
+    if width > COLS {
+      return Err(BoardError::OutOfBounds { row: 0, col: width - 1 });
+    }
+
+    let header = table.header.as_ref().map(|header| table.line(header));
+    let body_rows = ROWS - usize::from(header.is_some() && ROWS > 0);
+    if body_rows == 0 && all && !table.rows.is_empty() {
+      return Err(BoardError::OutOfBounds { row: ROWS, col: 0 });
+    }
+
+    let mut pages = Vec::new();
+    let mut rows = table.rows.iter().peekable();
+    while pages.is_empty() || (all && rows.peek().is_some()) {
+      let mut page = Self::default();
+      let lines = header
+        .iter()
+        .cloned()
+        .chain(rows.by_ref().take(body_rows).map(|row| table.line(row)));
+
+      for (row, line) in lines.take(ROWS).enumerate() {
+        page.write_codes(row, 0, &line)?;
+      }
+      pages.push(page);
+    }
+
+    Ok(pages)
+  }
+}
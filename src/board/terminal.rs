@@ -0,0 +1,191 @@
+use super::{color::Rgb, BoardData, BoardEdition, CharacterCode, DynBoardData};
+
+/// options for drawing a board in a terminal with [`BoardData::to_terminal`]
+///
+/// every cell is drawn with the same number of terminal columns, so the output lines up regardless of
+/// how the terminal measures emoji. the defaults draw a colored board with a border.
+#[derive(Debug, Clone)]
+pub struct TerminalOptions {
+  /// whether to use 24-bit ANSI colors. without colors, color tiles are drawn as lowercase letters:
+  /// `r`, `o`, `y`, `g`, `b`, `v`, `w`, and `k` for black, with `*` for filled
+  pub color: bool,
+  /// whether to draw a border around the board
+  pub border: bool,
+  /// whether to draw each cell one column wide instead of three
+  pub compact: bool,
+  /// the edition whose colors are used, which also decides the color of [`CharacterCode::Filled`]
+  pub edition: BoardEdition,
+}
+
+impl Default for TerminalOptions {
+  fn default() -> Self {
+    TerminalOptions {
+      color: true,
+      border: true,
+      compact: false,
+      edition: BoardEdition::default(),
+    }
+  }
+}
+
+impl TerminalOptions {
+  /// options without colors, for terminals without true-color support and for logs
+  pub fn plain() -> Self {
+    TerminalOptions {
+      color: false,
+      ..Default::default()
+    }
+  }
+
+  /// sets whether to use 24-bit ANSI colors
+  pub fn color(mut self, color: bool) -> Self {
+    self.color = color;
+    self
+  }
+
+  /// sets whether to draw a border around the board
+  pub fn border(mut self, border: bool) -> Self {
+    self.border = border;
+    self
+  }
+
+  /// sets whether to draw each cell one column wide
+  pub fn compact(mut self, compact: bool) -> Self {
+    self.compact = compact;
+    self
+  }
+
+  /// sets the edition whose colors are used
+  pub fn edition(mut self, edition: BoardEdition) -> Self {
+    self.edition = edition;
+    self
+  }
+}
+
+/// the ANSI escape that resets all colors
+const RESET: &str = "\x1b[0m";
+
+/// the ANSI escape that sets the background color
+fn background(Rgb(r, g, b): Rgb) -> String {
+  format!("\x1b[48;2;{r};{g};{b}m")
+}
+
+/// the ANSI escape that sets the foreground color
+fn foreground(Rgb(r, g, b): Rgb) -> String {
+  format!("\x1b[38;2;{r};{g};{b}m")
+}
+
+/// the single-width character for a code without colors
+fn plain_char(code: CharacterCode) -> char {
+  match code {
+    CharacterCode::Red => 'r',
+    CharacterCode::Orange => 'o',
+    CharacterCode::Yellow => 'y',
+    CharacterCode::Green => 'g',
+    CharacterCode::Blue => 'b',
+    CharacterCode::Violet => 'v',
+    CharacterCode::White => 'w',
+    CharacterCode::Black => 'k',
+    CharacterCode::Filled => '*',
+    CharacterCode::Newline => ' ',
+    code => code.into(),
+  }
+}
+
+/// draws `rows` of `cols` character codes. every line ends with a newline.
+pub(crate) fn draw_rows<'a>(rows: impl Iterator<Item = &'a [u8]>, cols: usize, options: &TerminalOptions) -> String {
+  let cell_width = if options.compact { 1 } else { 3 };
+  let inner_width = cols * cell_width;
+  let mut out = String::new();
+
+  let edge = |out: &mut String| {
+    if options.color {
+      out.push_str(&background(options.edition.bezel_color()));
+      out.push_str(&" ".repeat(inner_width + 2));
+      out.push_str(RESET);
+    } else {
+      out.push('+');
+      out.push_str(&"-".repeat(inner_width));
+      out.push('+');
+    }
+    out.push('\n');
+  };
+  let side = |out: &mut String| {
+    if options.color {
+      out.push_str(&background(options.edition.bezel_color()));
+      out.push(' ');
+    } else {
+      out.push('|');
+    }
+  };
+
+  if options.border {
+    edge(&mut out);
+  }
+
+  for row in rows {
+    if options.border {
+      side(&mut out);
+    }
+
+    for code in row.iter().take(cols).map(|code| CharacterCode::from_u8_lossy(*code)) {
+      let c = match (options.color, options.edition.tile_color(code)) {
+        (true, Some(color)) => {
+          out.push_str(&background(color));
+          ' '
+        }
+        (true, None) => {
+          out.push_str(&background(options.edition.flap_color()));
+          out.push_str(&foreground(options.edition.text_color()));
+          plain_char(code)
+        }
+        (false, _) => plain_char(code),
+      };
+
+      if options.compact {
+        out.push(c);
+      } else {
+        out.push(' ');
+        out.push(c);
+        out.push(' ');
+      }
+    }
+
+    if options.border {
+      side(&mut out);
+    }
+    if options.color {
+      out.push_str(RESET);
+    }
+    out.push('\n');
+  }
+
+  if options.border {
+    edge(&mut out);
+  }
+
+  out
+}
+
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// draws the board for a terminal, with one line per row and a border if enabled.
+  /// see [`TerminalOptions`]
+  ///
+  /// unlike the [`std::fmt::Display`] impl, every cell has a fixed width, so the output lines up in any
+  /// terminal and can be trusted in CLIs and test failure output.
+  ///
+  /// # example
+  /// ```
+  /// print!("{}", board.to_terminal(&TerminalOptions::default()));
+  /// ```
+  pub fn to_terminal(&self, options: &TerminalOptions) -> String {
+    draw_rows(self.0.iter().map(|row| &row[..]), COLS, options)
+  }
+}
+
+impl DynBoardData {
+  /// draws the board for a terminal. see [`BoardData::to_terminal`]
+  pub fn to_terminal(&self, options: &TerminalOptions) -> String {
+    draw_rows(self.iter_rows(), self.cols(), options)
+  }
+}
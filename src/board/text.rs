@@ -0,0 +1,196 @@
+use super::{
+  char::{char_to_code, code_to_char},
+  BoardData, BoardError, CharacterCode, DynBoardData,
+};
+
+/// writes a row of character codes as a string. characters are written as themselves, and the color
+/// codes and any code that is not a character are written as escapes such as `{63}`
+pub(crate) fn write_row(out: &mut String, row: &[u8]) {
+  for code in row {
+    match *code {
+      // the color tiles are wide in most fonts, so they are escaped to keep rows aligned
+      0..=62 if CharacterCode::is_cell_code(*code) => out.push(code_to_char(*code)),
+      code => {
+        out.push('{');
+        out.push_str(&code.to_string());
+        out.push('}');
+      }
+    }
+  }
+}
+
+/// reads the character codes of a row written by [`write_row`]. lowercase letters, the color emoji, and
+/// named escapes such as `{red}` are also accepted. `row` is only used for errors.
+///
+/// # errors
+/// - [`BoardError::InvalidChar`] if a character is not on the board or an escape is not closed
+/// - [`BoardError::InvalidCode`] if an escape is not a `u8` or the name of a code that can be displayed
+pub(crate) fn parse_row(line: &str, row: usize) -> Result<Vec<u8>, BoardError> {
+  let mut codes = Vec::new();
+  let mut chars = line.chars();
+
+  while let Some(c) = chars.next() {
+    let col = codes.len();
+    let code = if c == '{' {
+      let rest = chars.as_str();
+      let Some(end) = rest.find('}') else {
+        return Err(BoardError::InvalidChar {
+          row,
+          col,
+          value: format!("{{{rest}"),
+        });
+      };
+      let escape = &rest[..end];
+      chars = rest[end + 1..].chars();
+
+      // codes are read as they are, so boards with invalid cells read back what `write_row` wrote.
+      // see `invalid_cells` to check them
+      match escape.parse::<u8>() {
+        Ok(code) => code,
+        Err(_) => match escape.parse::<CharacterCode>() {
+          Ok(code) if CharacterCode::is_cell_code(code.into()) => code.into(),
+          _ => {
+            return Err(BoardError::InvalidCode {
+              row,
+              col,
+              value: escape.to_string(),
+            })
+          }
+        },
+      }
+    } else {
+      match char_to_code(c.to_ascii_uppercase()) {
+        Some(code) if CharacterCode::is_cell_code(code) => code,
+        _ => {
+          return Err(BoardError::InvalidChar {
+            row,
+            col,
+            value: c.to_string(),
+          })
+        }
+      }
+    };
+
+    codes.push(code);
+  }
+
+  Ok(codes)
+}
+
+/// reads a row written by [`write_row`] into `cells`, padding it with blanks if the row is short.
+/// see [`parse_row`]
+///
+/// # errors
+/// - [`BoardError::TooManyCols`] if the row has more cells than `cells`
+/// - [`BoardError::InvalidChar`] or [`BoardError::InvalidCode`] if a cell cannot be read
+pub(crate) fn read_row(line: &str, row: usize, cells: &mut [u8]) -> Result<(), BoardError> {
+  let codes = parse_row(line, row)?;
+  if codes.len() > cells.len() {
+    return Err(BoardError::TooManyCols {
+      row,
+      col: cells.len(),
+      expected: cells.len(),
+    });
+  }
+
+  cells[..codes.len()].copy_from_slice(&codes);
+  cells[codes.len()..].fill(CharacterCode::Blank.into());
+  Ok(())
+}
+
+/// writes rows of character codes in the text grid format, see [`BoardData::to_grid`]
+fn write_grid<'a>(rows: impl Iterator<Item = &'a [u8]>) -> String {
+  let mut grid = String::new();
+  for row in rows {
+    let end = row.iter().rposition(|code| *code != 0).map_or(0, |last| last + 1);
+    write_row(&mut grid, &row[..end]);
+    grid.push('\n');
+  }
+
+  grid
+}
+
+/// reads the text grid format into `rows` of `cols` cells, calling `put` with each line. rows without a
+/// line are left as they are, so they should start out blank
+///
+/// # errors
+/// - [`BoardError::TooManyRows`] if there are more than `rows` lines
+/// - [`BoardError::TooManyCols`] if a line has more than `cols` cells
+/// - [`BoardError::InvalidChar`] or [`BoardError::InvalidCode`] if a cell cannot be read
+fn read_grid(
+  s: &str,
+  rows: usize,
+  mut put: impl FnMut(usize, &str) -> Result<(), BoardError>,
+) -> Result<(), BoardError> {
+  for (row, line) in s.lines().enumerate() {
+    if row >= rows {
+      return Err(BoardError::TooManyRows { row, expected: rows });
+    }
+
+    put(row, line)?;
+  }
+
+  Ok(())
+}
+
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// prints the board in the text grid format, which [`BoardData::from_grid`] reads back.
+  ///
+  /// the grid has one line per row, and every line ends with a newline. characters are written as
+  /// themselves, and the color tiles are written as escapes of their code, such as `{63}` for red.
+  /// trailing blanks are left off every line, so grids survive editors that trim whitespace.
+  ///
+  /// # example
+  /// ```
+  /// let board = BoardData([[63, 8, 9], [0, 0, 0]]);
+  /// assert_eq!(board.to_grid(), "{63}HI\n\n");
+  /// ```
+  pub fn to_grid(&self) -> String {
+    write_grid(self.0.iter().map(|row| &row[..]))
+  }
+
+  /// parses a board in the text grid format, as printed by [`BoardData::to_grid`].
+  ///
+  /// every line is a row, and short lines are padded with blanks. missing rows at the end are blank, so
+  /// grids whose trailing blank lines were stripped by an editor still read back the same. a cell is a
+  /// character on the board, a lowercase letter, a color emoji such as `🟥`, or an escape of a code or
+  /// [`CharacterCode::name`], such as `{63}` or `{red}`. escapes of a code are read as they are, even if
+  /// the code is not on the board, so every board printed by [`BoardData::to_grid`] reads back the same.
+  /// use [`BoardData::validate`] to reject those boards.
+  ///
+  /// # errors
+  /// - [`BoardError::TooManyRows`] if there are more than `ROWS` lines
+  /// - [`BoardError::TooManyCols`] if a line has more than `COLS` cells
+  /// - [`BoardError::InvalidChar`] if a character is not on the board or an escape is not closed
+  /// - [`BoardError::InvalidCode`] if an escape is not a `u8` or the name of a code that can be displayed
+  pub fn from_grid(s: &str) -> Result<Self, BoardError> {
+    let mut board = Self::default();
+    read_grid(s, ROWS, |row, line| read_row(line, row, &mut board.0[row]))?;
+
+    Ok(board)
+  }
+}
+
+impl DynBoardData {
+  /// prints the board in the text grid format. see [`BoardData::to_grid`]
+  pub fn to_grid(&self) -> String {
+    write_grid(self.iter_rows())
+  }
+
+  /// parses a `rows`x`cols` board in the text grid format. see [`BoardData::from_grid`]
+  ///
+  /// # errors
+  /// - [`BoardError::TooLarge`] if `rows * cols` overflows a `usize`
+  /// - [`BoardError::TooManyRows`] if there are more than `rows` lines
+  /// - [`BoardError::TooManyCols`] if a line has more than `cols` cells
+  /// - [`BoardError::InvalidChar`] or [`BoardError::InvalidCode`] if a cell cannot be read
+  pub fn from_grid(s: &str, rows: usize, cols: usize) -> Result<Self, BoardError> {
+    let mut board = DynBoardData::try_new(rows, cols)?;
+    let cells = board.cells_mut();
+    read_grid(s, rows, |row, line| {
+      read_row(line, row, &mut cells[row * cols..(row + 1) * cols])
+    })?;
+
+    Ok(board)
+  }
+}
@@ -0,0 +1,146 @@
+use super::{canvas::cell_code, BoardData, BoardError, CharacterCode, DynBoardData, Rect};
+
+/// what happens to the cells that are shifted off the edge of a board by [`BoardData::shift`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeMode {
+  /// cells that leave one edge come back in on the opposite edge, like a ticker
+  Wrap,
+  /// cells that leave the board are dropped, and the cells left behind are filled with the code
+  Fill(CharacterCode),
+}
+
+/// finds the source index of `index` after shifting by `by` in a line of `len` cells, or `None` if
+/// it comes from outside the line
+fn source_index(index: usize, by: isize, len: usize, mode: EdgeMode) -> Option<usize> {
+  match mode {
+    // reduce the shift first so huge shifts cannot overflow
+    EdgeMode::Wrap => Some((index as isize - by.rem_euclid(len as isize)).rem_euclid(len as isize) as usize),
+    EdgeMode::Fill(_) => (index as isize)
+      .checked_sub(by)
+      .and_then(|source| usize::try_from(source).ok())
+      .filter(|source| *source < len),
+  }
+}
+
+/// transformation methods for moving, cropping, and mirroring boards. positions are `(row, col)` and
+/// zero-indexed.
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// iterates over every cell of the board as `(row, col, code)`, row by row
+  pub fn iter_cells(&self) -> impl Iterator<Item = (usize, usize, CharacterCode)> + '_ {
+    self.0.iter().enumerate().flat_map(|(row, codes)| {
+      codes
+        .iter()
+        .enumerate()
+        .map(move |(col, code)| (row, col, CharacterCode::from_u8_lossy(*code)))
+    })
+  }
+
+  /// moves every cell down by `rows` and right by `cols`. negative values move up and left.
+  /// see [`EdgeMode`] for what happens at the edges
+  ///
+  /// # example
+  /// ```
+  /// use vestaboard::board::{BoardData, EdgeMode};
+  ///
+  /// let mut board = BoardData::<6, 22>::default();
+  /// board.0[0][0] = 63;
+  ///
+  /// // scroll a ticker one cell to the left
+  /// board.shift(0, -1, EdgeMode::Wrap);
+  /// assert_eq!(board.0[0][21], 63);
+  /// ```
+  pub fn shift(&mut self, rows: isize, cols: isize, mode: EdgeMode) {
+    if ROWS == 0 || COLS == 0 {
+      return;
+    }
+
+    let fill = match mode {
+      EdgeMode::Fill(code) => cell_code(code),
+      EdgeMode::Wrap => 0,
+    };
+    let source = self.0;
+    for (row, codes) in self.0.iter_mut().enumerate() {
+      for (col, code) in codes.iter_mut().enumerate() {
+        *code = match (source_index(row, rows, ROWS, mode), source_index(col, cols, COLS, mode)) {
+          (Some(row), Some(col)) => source[row][col],
+          _ => fill,
+        };
+      }
+    }
+  }
+
+  /// flips the board left to right
+  pub fn flip_horizontal(&mut self) {
+    self.0.iter_mut().for_each(|row| row.reverse());
+  }
+
+  /// flips the board top to bottom
+  pub fn flip_vertical(&mut self) {
+    self.0.reverse();
+  }
+
+  /// copies the `NEW_ROWS`x`NEW_COLS` region with its top left corner at `row`, `col` into a smaller board
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the region does not fit on the board
+  pub fn crop<const NEW_ROWS: usize, const NEW_COLS: usize>(
+    &self,
+    row: usize,
+    col: usize,
+  ) -> Result<BoardData<NEW_ROWS, NEW_COLS>, BoardError> {
+    let mut cropped = BoardData::<NEW_ROWS, NEW_COLS>::default();
+    cropped.blit_region(self, Rect::new(row, col, NEW_ROWS, NEW_COLS), 0, 0, None)?;
+
+    Ok(cropped)
+  }
+
+  /// copies a region into a [`DynBoardData`], for regions whose size is only known at runtime
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the region does not fit on the board
+  pub fn region(&self, rect: Rect) -> Result<DynBoardData, BoardError> {
+    rect.check(ROWS, COLS)?;
+
+    let mut region = DynBoardData::new(rect.height, rect.width);
+    let cells = region.cells_mut();
+    for (index, (row, col)) in rect.cells().enumerate() {
+      cells[index] = self.0[row][col];
+    }
+
+    Ok(region)
+  }
+
+  /// places the board on a larger `NEW_ROWS`x`NEW_COLS` board with its top left corner at `row`, `col`.
+  /// the rest of the larger board is filled with `fill`
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the board does not fit on the larger board at `row`, `col`
+  pub fn embed<const NEW_ROWS: usize, const NEW_COLS: usize>(
+    &self,
+    row: usize,
+    col: usize,
+    fill: CharacterCode,
+  ) -> Result<BoardData<NEW_ROWS, NEW_COLS>, BoardError> {
+    let mut embedded = BoardData::<NEW_ROWS, NEW_COLS>::default();
+    embedded.fill(fill);
+    embedded.blit(self, row, col, None)?;
+
+    Ok(embedded)
+  }
+
+  /// places the board in the middle of a larger `NEW_ROWS`x`NEW_COLS` board filled with `fill`. when the
+  /// board cannot be exactly centered, it is placed one cell up and to the left of the center
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the board is larger than `NEW_ROWS`x`NEW_COLS`
+  pub fn pad<const NEW_ROWS: usize, const NEW_COLS: usize>(
+    &self,
+    fill: CharacterCode,
+  ) -> Result<BoardData<NEW_ROWS, NEW_COLS>, BoardError> {
+    self.embed(
+      NEW_ROWS.saturating_sub(ROWS) / 2,
+      NEW_COLS.saturating_sub(COLS) / 2,
+      fill,
+    )
+  }
+}
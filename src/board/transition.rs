@@ -0,0 +1,189 @@
+use std::time::Duration;
+
+use super::{BoardData, CharacterCode};
+
+/// the order of the flaps on the character wheel of each Vestaboard bit. a bit can only flip forward
+/// through this order, wrapping from the last flap back to [`CharacterCode::Blank`].
+pub const FLAP_ORDER: [CharacterCode; 66] = {
+  use CharacterCode::*;
+  [
+    Blank,
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+    I,
+    J,
+    K,
+    L,
+    M,
+    N,
+    O,
+    P,
+    Q,
+    R,
+    S,
+    T,
+    U,
+    V,
+    W,
+    X,
+    Y,
+    Z,
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Zero,
+    ExclamationMark,
+    AtSign,
+    PoundSign,
+    DollarSign,
+    LeftParen,
+    RightParen,
+    Hyphen,
+    PlusSign,
+    Ampersand,
+    EqualsSign,
+    Semicolon,
+    Colon,
+    SingleQuote,
+    DoubleQuote,
+    PercentSign,
+    Comma,
+    Period,
+    Slash,
+    QuestionMark,
+    DegreeSign,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Violet,
+    White,
+    Black,
+    Filled,
+  ]
+};
+
+/// the estimated time it takes a bit to flip from one flap to the next
+pub const DEFAULT_FLAP_DURATION: Duration = Duration::from_millis(60);
+
+/// the position of a code on the character wheel. codes that are not on the wheel are treated as blank.
+fn flap_index(code: u8) -> usize {
+  let code = CharacterCode::from_u8_lossy(code);
+  FLAP_ORDER.iter().position(|flap| *flap == code).unwrap_or(0)
+}
+
+/// a simulation of the split-flap transition between two boards, created with [`BoardData::transition_to`].
+///
+/// every bit starts flipping at the same time and flips forward through [`FLAP_ORDER`] one flap at a
+/// time until it shows its new code, so the board settles when the bit with the most flaps is done.
+#[derive(Debug, Clone)]
+pub struct Transition<const ROWS: usize, const COLS: usize> {
+  /// the wheel position of every cell on the starting board
+  start: [[usize; COLS]; ROWS],
+  /// the number of flaps every cell flips through
+  flaps: [[usize; COLS]; ROWS],
+  /// the duration of a single flap
+  flap_duration: Duration,
+}
+
+impl<const ROWS: usize, const COLS: usize> Transition<ROWS, COLS> {
+  /// simulates the transition from one board to another with the [`DEFAULT_FLAP_DURATION`]
+  pub fn new(from: &BoardData<ROWS, COLS>, to: &BoardData<ROWS, COLS>) -> Self {
+    let start = from.0.map(|row| row.map(flap_index));
+    let mut flaps = [[0; COLS]; ROWS];
+
+    for (row, cells) in flaps.iter_mut().enumerate() {
+      for (col, cell) in cells.iter_mut().enumerate() {
+        let end = flap_index(to.0[row][col]);
+        *cell = (end + FLAP_ORDER.len() - start[row][col]) % FLAP_ORDER.len();
+      }
+    }
+
+    Transition {
+      start,
+      flaps,
+      flap_duration: DEFAULT_FLAP_DURATION,
+    }
+  }
+
+  /// sets the duration of a single flap used by [`Transition::estimated_duration`]
+  pub fn with_flap_duration(mut self, flap_duration: Duration) -> Self {
+    self.flap_duration = flap_duration;
+    self
+  }
+
+  /// the number of flaps each cell flips through
+  pub fn cell_flaps(&self) -> &[[usize; COLS]; ROWS] {
+    &self.flaps
+  }
+
+  /// the total number of flaps across all cells. a lower count is a quieter transition.
+  pub fn flap_count(&self) -> usize {
+    self.flaps.iter().flatten().sum()
+  }
+
+  /// the number of cells that flip at all
+  pub fn moving_cells(&self) -> usize {
+    self.flaps.iter().flatten().filter(|flaps| **flaps > 0).count()
+  }
+
+  /// the number of steps until every cell has settled, which is the most flaps of any cell
+  pub fn steps(&self) -> usize {
+    self.flaps.iter().flatten().copied().max().unwrap_or(0)
+  }
+
+  /// the estimated time until every cell has settled
+  pub fn estimated_duration(&self) -> Duration {
+    self.flap_duration * self.steps() as u32
+  }
+
+  /// the board as it looks after `step` flaps. step 0 is the starting board, and any step at or
+  /// after [`Transition::steps`] is the final board.
+  pub fn frame(&self, step: usize) -> BoardData<ROWS, COLS> {
+    let mut board = [[0; COLS]; ROWS];
+
+    for (row, cells) in board.iter_mut().enumerate() {
+      for (col, cell) in cells.iter_mut().enumerate() {
+        let position = self.start[row][col] + step.min(self.flaps[row][col]);
+        *cell = FLAP_ORDER[position % FLAP_ORDER.len()].into();
+      }
+    }
+
+    BoardData(board)
+  }
+
+  /// iterates over every frame of the transition, from the starting board to the final board
+  pub fn frames(&self) -> impl Iterator<Item = BoardData<ROWS, COLS>> + '_ {
+    (0..=self.steps()).map(|step| self.frame(step))
+  }
+}
+
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// simulates the split-flap transition from this board to `to`. see [`Transition`]
+  ///
+  /// # example
+  /// ```
+  /// let transition = current.transition_to(&next);
+  /// println!("{} flaps over {:?}", transition.flap_count(), transition.estimated_duration());
+  ///
+  /// for frame in transition.frames() {
+  ///   println!("{frame}");
+  /// }
+  /// ```
+  pub fn transition_to(&self, to: &BoardData<ROWS, COLS>) -> Transition<ROWS, COLS> {
+    Transition::new(self, to)
+  }
+}
@@ -0,0 +1,215 @@
+use super::{char::char_to_code, CharacterCode};
+
+/// a character of the input to [`transliterate`] that is not on the board
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Replacement {
+  /// the character was replaced with characters that are on the board
+  Substituted {
+    /// the byte index of the character in the input
+    index: usize,
+    /// the character of the input
+    original: char,
+    /// the characters it was replaced with
+    replacement: &'static str,
+  },
+  /// the character has no replacement and was removed
+  Dropped {
+    /// the byte index of the character in the input
+    index: usize,
+    /// the character of the input
+    original: char,
+  },
+}
+
+impl Replacement {
+  /// the byte index of the character in the input
+  pub fn index(&self) -> usize {
+    match self {
+      Replacement::Substituted { index, .. } | Replacement::Dropped { index, .. } => *index,
+    }
+  }
+
+  /// the character of the input
+  pub fn original(&self) -> char {
+    match self {
+      Replacement::Substituted { original, .. } | Replacement::Dropped { original, .. } => *original,
+    }
+  }
+}
+
+/// the result of [`transliterate`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Transliteration {
+  /// the transliterated text, which only contains characters that are on the board
+  pub text: String,
+  /// the characters of the input that were substituted or dropped, in order
+  pub replacements: Vec<Replacement>,
+}
+
+impl Transliteration {
+  /// the character codes of the transliterated text
+  pub fn codes(&self) -> Vec<CharacterCode> {
+    self.text.chars().map(CharacterCode::from_char_lossy).collect()
+  }
+
+  /// whether every character of the input is on the board, apart from lowercase letters
+  pub fn is_exact(&self) -> bool {
+    self.replacements.is_empty()
+  }
+
+  /// the characters of the input that were removed
+  pub fn dropped(&self) -> impl Iterator<Item = &Replacement> {
+    self
+      .replacements
+      .iter()
+      .filter(|replacement| matches!(replacement, Replacement::Dropped { .. }))
+  }
+
+  /// the characters of the input that were replaced
+  pub fn substituted(&self) -> impl Iterator<Item = &Replacement> {
+    self
+      .replacements
+      .iter()
+      .filter(|replacement| matches!(replacement, Replacement::Substituted { .. }))
+  }
+}
+
+/// converts text to the characters that are on the board.
+///
+/// lowercase letters are uppercased. accented letters are folded (`é` to `E`, `ß` to `SS`), smart
+/// punctuation is mapped to its plain counterpart (`“` to `"`, `—` to `-`, `…` to `...`), and symbols
+/// are spelled out where possible (`×` to `X`, `€` to `EUR`, `½` to `1/2`). characters without a
+/// replacement, such as most emoji, are dropped. invisible formatting characters such as `\r` and
+/// zero-width spaces are removed without being reported.
+///
+/// # example
+/// ```
+/// let result = transliterate("café × 2");
+/// assert_eq!(result.text, "CAFE X 2");
+/// for replacement in &result.replacements {
+///   tracing::warn!("replaced {:?} at {}", replacement.original(), replacement.index());
+/// }
+/// ```
+pub fn transliterate(text: &str) -> Transliteration {
+  let mut result = Transliteration {
+    text: String::with_capacity(text.len()),
+    replacements: Vec::new(),
+  };
+
+  for (index, original) in text.char_indices() {
+    let upper = original.to_ascii_uppercase();
+    if char_to_code(upper).is_some() {
+      result.text.push(upper);
+      continue;
+    }
+
+    if is_invisible(original) {
+      continue;
+    }
+
+    match substitute(original) {
+      Some(replacement) => {
+        result.text.push_str(replacement);
+        result.replacements.push(Replacement::Substituted {
+          index,
+          original,
+          replacement,
+        });
+      }
+      None => result.replacements.push(Replacement::Dropped { index, original }),
+    }
+  }
+
+  result
+}
+
+/// whether a character is invisible formatting that can be removed without changing the text
+fn is_invisible(c: char) -> bool {
+  matches!(
+    c,
+    '\r' | '\u{200b}' | '\u{200c}' | '\u{200d}' | '\u{2060}' | '\u{feff}' | '\u{fe0e}' | '\u{fe0f}'
+  )
+}
+
+/// the replacement for a character that is not on the board, made up of characters that are on the board
+pub(crate) fn substitute(c: char) -> Option<&'static str> {
+  let replacement = match c {
+    // letters
+    'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' | 'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' | 'ª' => {
+      "A"
+    }
+    'Æ' | 'æ' => "AE",
+    'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' | 'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "C",
+    'Ď' | 'Đ' | 'Ð' | 'ď' | 'đ' | 'ð' => "D",
+    'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' | 'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => {
+      "E"
+    }
+    'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' | 'ĝ' | 'ğ' | 'ġ' | 'ģ' => "G",
+    'Ĥ' | 'Ħ' | 'ĥ' | 'ħ' => "H",
+    'Ì' | 'Í' | 'Î' | 'Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' | 'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => {
+      "I"
+    }
+    'Ĳ' | 'ĳ' => "IJ",
+    'Ĵ' | 'ĵ' => "J",
+    'Ķ' | 'ķ' => "K",
+    'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' | 'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "L",
+    'Ñ' | 'Ń' | 'Ņ' | 'Ň' | 'ñ' | 'ń' | 'ņ' | 'ň' => "N",
+    'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' | 'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => {
+      "O"
+    }
+    'Œ' | 'œ' => "OE",
+    'Ŕ' | 'Ŗ' | 'Ř' | 'ŕ' | 'ŗ' | 'ř' => "R",
+    'Ś' | 'Ŝ' | 'Ş' | 'Š' | 'ś' | 'ŝ' | 'ş' | 'š' => "S",
+    'ß' | 'ẞ' => "SS",
+    'Ţ' | 'Ť' | 'Ŧ' | 'ţ' | 'ť' | 'ŧ' => "T",
+    'Þ' | 'þ' => "TH",
+    'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' | 'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű'
+    | 'ų' => "U",
+    'Ŵ' | 'ŵ' => "W",
+    'Ý' | 'Ÿ' | 'Ŷ' | 'ý' | 'ÿ' | 'ŷ' => "Y",
+    'Ź' | 'Ż' | 'Ž' | 'ź' | 'ż' | 'ž' => "Z",
+    // punctuation
+    '‘' | '’' | '‚' | '‛' | '′' | '`' | '´' => "'",
+    '“' | '”' | '„' | '‟' | '″' | '«' | '»' => "\"",
+    '‐' | '‑' | '‒' | '–' | '—' | '―' | '−' | '~' | '_' | '•' => "-",
+    '…' => "...",
+    '·' => ".",
+    '[' | '{' => "(",
+    ']' | '}' => ")",
+    '\\' | '|' => "/",
+    '¡' => "!",
+    '¿' => "?",
+    'º' | '˚' => "°",
+    '\t' | '\u{a0}' | '\u{2002}'..='\u{200a}' | '\u{202f}' | '\u{205f}' | '\u{3000}' => " ",
+    // symbols
+    '×' => "X",
+    '÷' => "/",
+    '±' => "+/-",
+    '€' => "EUR",
+    '£' => "GBP",
+    '¥' => "YEN",
+    '¢' => "C",
+    '©' => "(C)",
+    '®' => "(R)",
+    '™' => "TM",
+    '№' => "NO.",
+    '¼' => "1/4",
+    '½' => "1/2",
+    '¾' => "3/4",
+    '¹' => "1",
+    '²' => "2",
+    '³' => "3",
+    // colors
+    '🔴' => "🟥",
+    '🟠' => "🟧",
+    '🟡' => "🟨",
+    '🟢' => "🟩",
+    '🔵' => "🟦",
+    '🟣' => "🟪",
+    '⚪' => "⬜",
+    '⚫' => "⬛",
+    _ => return None,
+  };
+
+  Some(replacement)
+}
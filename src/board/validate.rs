@@ -0,0 +1,103 @@
+use super::{BoardData, BoardError, CharacterCode, DynBoardData};
+
+/// how parsers handle values that are not character codes, such as code 43 or a character that is not
+/// on the board. the VBML parser also uses it for content that does not fit, see
+/// [`crate::vbml::VbmlParser::layout`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodeHandling {
+  /// invalid values are an error
+  Strict,
+  /// invalid values are replaced with [`CharacterCode::Blank`], and content that does not fit is cut off
+  Lossy,
+}
+
+/// a cell of a board whose code cannot be displayed, found by [`BoardData::validate`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidCell {
+  /// the row of the cell
+  pub row: usize,
+  /// the column of the cell
+  pub col: usize,
+  /// the code of the cell
+  pub code: u8,
+}
+
+impl std::fmt::Display for InvalidCell {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "({}, {}): {}", self.row, self.col, self.code)
+  }
+}
+
+/// finds the invalid cells of rows of character codes
+fn invalid_cells<'a>(rows: impl Iterator<Item = &'a [u8]>) -> Vec<InvalidCell> {
+  rows
+    .enumerate()
+    .flat_map(|(row, codes)| {
+      codes
+        .iter()
+        .enumerate()
+        .filter(|(_, code)| !CharacterCode::is_cell_code(**code))
+        .map(move |(col, code)| InvalidCell { row, col, code: *code })
+    })
+    .collect()
+}
+
+/// replaces the invalid codes of a board with [`CharacterCode::Blank`]
+fn sanitize<'a>(cells: impl Iterator<Item = &'a mut u8>) {
+  cells
+    .filter(|code| !CharacterCode::is_cell_code(**code))
+    .for_each(|code| *code = CharacterCode::Blank.into());
+}
+
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// lists every cell whose code is not a [`CharacterCode`] that can be displayed, row by row.
+  /// see [`CharacterCode::is_cell_code`]
+  pub fn invalid_cells(&self) -> Vec<InvalidCell> {
+    invalid_cells(self.0.iter().map(|row| &row[..]))
+  }
+
+  /// checks that every cell of the board can be displayed. boards built from raw codes, such as with
+  /// [`BoardData::from`] or serde, are not validated until this is called.
+  ///
+  /// # errors
+  /// - [`BoardError::InvalidCells`] with every invalid cell if there are any
+  pub fn validate(&self) -> Result<(), BoardError> {
+    let cells = self.invalid_cells();
+    if !cells.is_empty() {
+      return Err(BoardError::InvalidCells(cells));
+    }
+
+    Ok(())
+  }
+
+  /// replaces every invalid cell with [`CharacterCode::Blank`]
+  pub fn sanitize(&mut self) {
+    sanitize(self.0.iter_mut().flatten());
+  }
+}
+
+impl DynBoardData {
+  /// lists every cell whose code is not a [`CharacterCode`] that can be displayed.
+  /// see [`BoardData::invalid_cells`]
+  pub fn invalid_cells(&self) -> Vec<InvalidCell> {
+    invalid_cells(self.iter_rows())
+  }
+
+  /// checks that every cell of the board can be displayed. see [`BoardData::validate`]
+  ///
+  /// # errors
+  /// - [`BoardError::InvalidCells`] with every invalid cell if there are any
+  pub fn validate(&self) -> Result<(), BoardError> {
+    let cells = self.invalid_cells();
+    if !cells.is_empty() {
+      return Err(BoardError::InvalidCells(cells));
+    }
+
+    Ok(())
+  }
+
+  /// replaces every invalid cell with [`CharacterCode::Blank`]
+  pub fn sanitize(&mut self) {
+    sanitize(self.cells_mut().iter_mut());
+  }
+}
@@ -0,0 +1,476 @@
+use super::{canvas::cell_code, transliterate, BoardData, BoardError, CharacterCode, Rect};
+
+/// colors a value green, yellow, or red, for the widgets of [`BoardData`] such as
+/// [`BoardData::draw_progress_bar`]
+///
+/// when `yellow` is less than or equal to `red`, higher values are worse: values at or above `red` are red,
+/// values at or above `yellow` are yellow, and anything lower is green, such as for a queue depth. when
+/// `yellow` is greater than `red`, lower values are worse: values at or below `red` are red, values at or
+/// below `yellow` are yellow, and anything higher is green, such as for a sales goal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Thresholds {
+  /// the value where the color changes from green to yellow
+  pub yellow: f64,
+  /// the value where the color changes from yellow to red
+  pub red: f64,
+}
+
+impl Thresholds {
+  /// creates new [`Thresholds`]
+  pub fn new(yellow: f64, red: f64) -> Self {
+    Thresholds { yellow, red }
+  }
+
+  /// the color of a value
+  pub fn color(&self, value: f64) -> CharacterCode {
+    let (yellow, red) = if self.yellow <= self.red {
+      (value >= self.yellow, value >= self.red)
+    } else {
+      (value <= self.yellow, value <= self.red)
+    };
+
+    match (yellow, red) {
+      (_, true) => CharacterCode::Red,
+      (true, false) => CharacterCode::Yellow,
+      (false, false) => CharacterCode::Green,
+    }
+  }
+}
+
+/// the color of the filled cells of a widget
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WidgetColor {
+  /// every value has the same color
+  Solid(CharacterCode),
+  /// the color depends on the value
+  Thresholds(Thresholds),
+}
+
+impl Default for WidgetColor {
+  fn default() -> Self {
+    WidgetColor::Solid(CharacterCode::Green)
+  }
+}
+
+impl WidgetColor {
+  /// the color of a value
+  fn of(&self, value: f64) -> CharacterCode {
+    match self {
+      WidgetColor::Solid(code) => *code,
+      WidgetColor::Thresholds(thresholds) => thresholds.color(value),
+    }
+  }
+}
+
+impl From<CharacterCode> for WidgetColor {
+  fn from(code: CharacterCode) -> Self {
+    WidgetColor::Solid(code)
+  }
+}
+
+impl From<Thresholds> for WidgetColor {
+  fn from(thresholds: Thresholds) -> Self {
+    WidgetColor::Thresholds(thresholds)
+  }
+}
+
+/// the direction the bars of a [`BarChart`] grow in
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+  /// one bar per row, growing to the right
+  #[default]
+  Horizontal,
+  /// one bar per column, growing up
+  Vertical,
+}
+
+/// a progress bar for [`BoardData::draw_progress_bar`], with an optional label on the left and the percent
+/// done on the right
+#[derive(Debug, Clone)]
+pub struct ProgressBar {
+  /// the progress, from 0 to `max`
+  pub value: f64,
+  /// the value of a full bar
+  pub max: f64,
+  /// the text before the bar
+  pub label: Option<String>,
+  /// whether to show the percent done after the bar, such as `75%`
+  pub show_value: bool,
+  /// the color of the filled cells. thresholds are compared with `value`
+  pub color: WidgetColor,
+  /// the code of the cells that are not filled
+  pub empty: CharacterCode,
+}
+
+impl ProgressBar {
+  /// creates a green progress bar at `value` out of `max`, showing the percent done
+  pub fn new(value: f64, max: f64) -> Self {
+    ProgressBar {
+      value,
+      max,
+      label: None,
+      show_value: true,
+      color: WidgetColor::default(),
+      empty: CharacterCode::Blank,
+    }
+  }
+
+  /// sets the text before the bar
+  pub fn label(mut self, label: impl Into<String>) -> Self {
+    self.label = Some(label.into());
+    self
+  }
+
+  /// sets whether to show the percent done after the bar
+  pub fn show_value(mut self, show_value: bool) -> Self {
+    self.show_value = show_value;
+    self
+  }
+
+  /// sets the color of the filled cells, either a [`CharacterCode`] or [`Thresholds`]
+  pub fn color(mut self, color: impl Into<WidgetColor>) -> Self {
+    self.color = color.into();
+    self
+  }
+
+  /// sets the code of the cells that are not filled
+  pub fn empty(mut self, empty: CharacterCode) -> Self {
+    self.empty = empty;
+    self
+  }
+}
+
+/// a bar of a [`BarChart`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bar {
+  /// the name of the bar. horizontal charts show it before the bar, and vertical charts show its first
+  /// character below the bar
+  pub label: String,
+  /// the value of the bar
+  pub value: f64,
+}
+
+impl Bar {
+  /// creates a new [`Bar`]
+  pub fn new(label: impl Into<String>, value: f64) -> Self {
+    Bar {
+      label: label.into(),
+      value,
+    }
+  }
+}
+
+/// a bar chart for [`BoardData::draw_bar_chart`]
+#[derive(Debug, Clone)]
+pub struct BarChart {
+  /// the bars, from top to bottom or left to right
+  pub bars: Vec<Bar>,
+  /// the direction the bars grow in
+  pub orientation: Orientation,
+  /// the value of a full bar. if `None`, the largest value is a full bar
+  pub max: Option<f64>,
+  /// whether horizontal charts show the value after every bar. vertical charts have no room for values
+  pub show_values: bool,
+  /// the color of the bars. thresholds are compared with the value of each bar
+  pub color: WidgetColor,
+}
+
+impl BarChart {
+  /// creates a horizontal chart of green bars, showing their values
+  pub fn new(bars: Vec<Bar>) -> Self {
+    BarChart {
+      bars,
+      orientation: Orientation::Horizontal,
+      max: None,
+      show_values: true,
+      color: WidgetColor::default(),
+    }
+  }
+
+  /// sets the direction the bars grow in
+  pub fn orientation(mut self, orientation: Orientation) -> Self {
+    self.orientation = orientation;
+    self
+  }
+
+  /// sets the value of a full bar
+  pub fn max(mut self, max: f64) -> Self {
+    self.max = Some(max);
+    self
+  }
+
+  /// sets whether horizontal charts show the value after every bar
+  pub fn show_values(mut self, show_values: bool) -> Self {
+    self.show_values = show_values;
+    self
+  }
+
+  /// sets the color of the bars, either a [`CharacterCode`] or [`Thresholds`]
+  pub fn color(mut self, color: impl Into<WidgetColor>) -> Self {
+    self.color = color.into();
+    self
+  }
+}
+
+/// a sparkline for [`BoardData::draw_sparkline`]. every value is a column of cells whose height follows
+/// the value, so it is best drawn in a region two or more rows tall
+#[derive(Debug, Clone)]
+pub struct Sparkline {
+  /// the values, oldest first. only the newest values that fit in the region are drawn
+  pub values: Vec<f64>,
+  /// the value of an empty column. if `None`, the smallest value drawn
+  pub min: Option<f64>,
+  /// the value of a full column. if `None`, the largest value drawn
+  pub max: Option<f64>,
+  /// the color of the columns. thresholds are compared with each value
+  pub color: WidgetColor,
+}
+
+impl Sparkline {
+  /// creates a green sparkline scaled to its values
+  pub fn new(values: Vec<f64>) -> Self {
+    Sparkline {
+      values,
+      min: None,
+      max: None,
+      color: WidgetColor::default(),
+    }
+  }
+
+  /// sets the values of an empty and a full column
+  pub fn range(mut self, min: f64, max: f64) -> Self {
+    self.min = Some(min);
+    self.max = Some(max);
+    self
+  }
+
+  /// sets the color of the columns, either a [`CharacterCode`] or [`Thresholds`]
+  pub fn color(mut self, color: impl Into<WidgetColor>) -> Self {
+    self.color = color.into();
+    self
+  }
+}
+
+/// the number of `cells` that `value` fills between `min` and `max`, rounded to the nearest cell
+fn scale(value: f64, min: f64, max: f64, cells: usize) -> usize {
+  let fraction = (value - min) / (max - min);
+  if !fraction.is_finite() {
+    return if value >= max && value > min { cells } else { 0 };
+  }
+
+  (fraction.clamp(0.0, 1.0) * cells as f64).round() as usize
+}
+
+/// formats a value in at most 5 characters where possible, such as `42`, `3.5`, `12K`, or `1.2M`
+fn format_value(value: f64) -> String {
+  let magnitude = value.abs();
+  let (value, suffix) = match magnitude {
+    m if m >= 1e9 => (value / 1e9, "B"),
+    m if m >= 1e6 => (value / 1e6, "M"),
+    m if m >= 1e4 => (value / 1e3, "K"),
+    _ => (value, ""),
+  };
+
+  if value.fract() == 0.0 || value.abs() >= 100.0 {
+    format!("{value:.0}{suffix}")
+  } else {
+    format!("{value:.1}{suffix}")
+  }
+}
+
+/// converts text to character codes, cut off at `width` cells
+fn text_codes(text: &str, width: usize) -> Vec<CharacterCode> {
+  let mut codes = transliterate(text).codes();
+  codes.truncate(width);
+  codes
+}
+
+/// widgets for dashboards, drawn into a region of the board with the color codes. text is converted with
+/// [`super::transliterate()`], and labels and values are shortened or left off so that every bar keeps at
+/// least one cell.
+///
+/// every method checks its bounds before changing the board, so a method that returns
+/// [`BoardError::OutOfBounds`] leaves the board untouched.
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// draws a [`ProgressBar`] across the region. the bar fills every row of the region, and the label and
+  /// value are drawn on its middle row
+  ///
+  /// # example
+  /// ```
+  /// let bar = ProgressBar::new(7_500.0, 10_000.0).label("sales").color(Thresholds::new(5_000.0, 2_500.0));
+  /// board.draw_progress_bar(Rect::new(2, 0, 1, 22), &bar)?; // SALES ████████···· 75%
+  /// ```
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the region does not fit on the board
+  pub fn draw_progress_bar(&mut self, rect: Rect, bar: &ProgressBar) -> Result<(), BoardError> {
+    rect.check(ROWS, COLS)?;
+    if rect.is_empty() {
+      return Ok(());
+    }
+
+    let percent = scale(bar.value, 0.0, bar.max, 100);
+    let mut value = match bar.show_value {
+      true => text_codes(&format!("{percent}%"), rect.width),
+      false => Vec::new(),
+    };
+    if value.len() + 2 > rect.width {
+      value.clear();
+    }
+    let value_width = if value.is_empty() { 0 } else { value.len() + 1 };
+
+    // the label is followed by a space and at least one cell of the bar
+    let label = text_codes(
+      bar.label.as_deref().unwrap_or(""),
+      (rect.width - value_width).saturating_sub(2),
+    );
+    let label_width = if label.is_empty() { 0 } else { label.len() + 1 };
+
+    let bar_col = rect.col + label_width;
+    let bar_width = rect.width - label_width - value_width;
+    let filled = scale(bar.value, 0.0, bar.max, bar_width);
+    let color = bar.color.of(bar.value);
+
+    let middle = rect.row + (rect.height - 1) / 2;
+    for (row, col) in rect.cells() {
+      let code = if col < bar_col {
+        label.get(col - rect.col).filter(|_| row == middle).copied()
+      } else if col < bar_col + bar_width {
+        Some(if col < bar_col + filled { color } else { bar.empty })
+      } else {
+        // the value starts after a space
+        (col - bar_col - bar_width)
+          .checked_sub(1)
+          .and_then(|index| value.get(index))
+          .filter(|_| row == middle)
+          .copied()
+      };
+
+      self.0[row][col] = cell_code(code.unwrap_or(CharacterCode::Blank));
+    }
+
+    Ok(())
+  }
+
+  /// draws a [`BarChart`] in the region.
+  ///
+  /// horizontal charts draw one bar per row, with the labels in a column on the left and the values in a
+  /// column on the right. vertical charts draw one bar per column, growing up from the bottom, with a
+  /// blank column between bars and the first character of every label on the bottom row.
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the region does not fit on the board, or the bars do not fit in the
+  ///   region
+  pub fn draw_bar_chart(&mut self, rect: Rect, chart: &BarChart) -> Result<(), BoardError> {
+    rect.check(ROWS, COLS)?;
+    if chart.bars.is_empty() {
+      return Ok(());
+    }
+
+    let max = chart
+      .max
+      .unwrap_or_else(|| chart.bars.iter().map(|bar| bar.value).fold(0.0, f64::max));
+
+    match chart.orientation {
+      Orientation::Horizontal => {
+        if chart.bars.len() > rect.height || rect.width == 0 {
+          return Err(BoardError::OutOfBounds {
+            row: rect.row + chart.bars.len() - 1,
+            col: rect.col,
+          });
+        }
+
+        let mut values = chart
+          .bars
+          .iter()
+          .map(|bar| text_codes(&format_value(bar.value), rect.width))
+          .collect::<Vec<_>>();
+        let mut value_width = values.iter().map(Vec::len).max().unwrap_or(0) + 1;
+        if !chart.show_values || value_width + 1 > rect.width {
+          values.iter_mut().for_each(Vec::clear);
+          value_width = 0;
+        }
+
+        let labels = chart
+          .bars
+          .iter()
+          .map(|bar| text_codes(&bar.label, (rect.width - value_width - 1) / 2))
+          .collect::<Vec<_>>();
+        let label_width = match labels.iter().map(Vec::len).max().unwrap_or(0) {
+          0 => 0,
+          width => width + 1,
+        };
+
+        let bar_width = rect.width - label_width - value_width;
+        self.fill_rect(rect, CharacterCode::Blank)?;
+        for (index, bar) in chart.bars.iter().enumerate() {
+          let row = rect.row + index;
+          let filled = scale(bar.value, 0.0, max, bar_width);
+          let value = &values[index];
+
+          self.write_codes(row, rect.col, &labels[index])?;
+          self.draw_hline(row, rect.col + label_width, filled, chart.color.of(bar.value))?;
+          self.write_codes(row, rect.col + rect.width - value.len(), value)?;
+        }
+      }
+      Orientation::Vertical => {
+        let width = chart.bars.len() * 2 - 1;
+        let has_labels = chart.bars.iter().any(|bar| !bar.label.is_empty());
+        let height = rect.height.saturating_sub(usize::from(has_labels));
+        if width > rect.width || height == 0 {
+          return Err(BoardError::OutOfBounds {
+            row: rect.row + rect.height.max(1) - 1,
+            col: rect.col + width - 1,
+          });
+        }
+
+        self.fill_rect(rect, CharacterCode::Blank)?;
+        for (index, bar) in chart.bars.iter().enumerate() {
+          let col = rect.col + index * 2;
+          let filled = scale(bar.value, 0.0, max, height);
+          self.draw_vline(rect.row + height - filled, col, filled, chart.color.of(bar.value))?;
+
+          if has_labels {
+            self.write_codes(rect.row + height, col, &text_codes(&bar.label, 1))?;
+          }
+        }
+      }
+    }
+
+    Ok(())
+  }
+
+  /// draws a [`Sparkline`] in the region, with the newest value in the rightmost column. every value
+  /// fills at least one cell, so values are still visible in a region one row tall
+  ///
+  /// # errors
+  /// - [`BoardError::OutOfBounds`] if the region does not fit on the board
+  pub fn draw_sparkline(&mut self, rect: Rect, sparkline: &Sparkline) -> Result<(), BoardError> {
+    rect.check(ROWS, COLS)?;
+    if rect.is_empty() {
+      return Ok(());
+    }
+
+    let values = &sparkline.values[sparkline.values.len().saturating_sub(rect.width)..];
+    let min = sparkline
+      .min
+      .unwrap_or_else(|| values.iter().copied().fold(f64::INFINITY, f64::min));
+    let max = sparkline
+      .max
+      .unwrap_or_else(|| values.iter().copied().fold(f64::NEG_INFINITY, f64::max));
+
+    self.fill_rect(rect, CharacterCode::Blank)?;
+    let first_col = rect.col + rect.width - values.len();
+    for (index, value) in values.iter().enumerate() {
+      let filled = scale(*value, min, max, rect.height - 1) + 1;
+      self.draw_vline(
+        rect.row + rect.height - filled,
+        first_col + index,
+        filled,
+        sparkline.color.of(*value),
+      )?;
+    }
+
+    Ok(())
+  }
+}
@@ -0,0 +1,98 @@
+//! # common board client (requires the `rw`, `subscription`, or `local` feature)
+//!
+//! this module contains the [`BoardClient`] trait, a shared async interface over the read/write,
+//! subscription, and local apis. writing services against [`BoardClient`] instead of a concrete
+//! [`Vestaboard`](crate::Vestaboard) config lets the backend be swapped through configuration.
+//!
+//! ## methods
+//! ```
+//! async fn read_board(&self) -> Result<BoardData<ROWS, COLS>, BoardClientError>
+//! async fn write_board(&self, message: BoardData<ROWS, COLS>) -> Result<Option<String>, BoardClientError> // returns the message id, if any
+//! async fn health_check(&self) -> Result<(), BoardClientError>
+//! ```
+//!
+//! ## implementors
+//! - `Vestaboard<RWConfig, ROWS, COLS>` (requires the `rw` feature)
+//! - `SubscriptionBoard<ROWS, COLS>` (requires the `subscription` feature), created with
+//!   `Vestaboard::<SubscriptionConfig>::subscription`. the subscription api cannot read a board, so
+//!   [`BoardClient::read_board`] returns [`BoardClientError::Unsupported`]
+//! - `Vestaboard<LocalConfig, ROWS, COLS>` (requires the `local` feature)
+//!
+//! ## example
+//! ```
+//! async fn post<C: BoardClient>(client: &C, message: BoardData) -> Result<(), BoardClientError> {
+//!   client.health_check().await?;
+//!   client.write_board(message).await?;
+//!   Ok(())
+//! }
+//! ```
+
+use std::future::Future;
+
+use thiserror::Error;
+
+use crate::board::{BoardData, FLAGSHIP_COLS, FLAGSHIP_ROWS};
+
+/// a shared async interface implemented by every Vestaboard api backend
+///
+/// # type parameters
+/// - `ROWS`: the number of rows in the board, defaults to the flagship board size
+/// - `COLS`: the number of columns in the board, defaults to the flagship board size
+pub trait BoardClient<const ROWS: usize = FLAGSHIP_ROWS, const COLS: usize = FLAGSHIP_COLS> {
+  /// read the message that is currently on the Vestaboard
+  ///
+  /// # errors
+  /// - [`BoardClientError::Unsupported`] if the backend cannot read the board
+  /// - the wrapped backend error if the request fails
+  fn read_board(&self) -> impl Future<Output = Result<BoardData<ROWS, COLS>, BoardClientError>> + Send;
+
+  /// write a message to the Vestaboard
+  ///
+  /// # returns
+  /// the id of the written message if the backend provides one
+  ///
+  /// # errors
+  /// - the wrapped backend error if the request fails
+  fn write_board(
+    &self,
+    message: BoardData<ROWS, COLS>,
+  ) -> impl Future<Output = Result<Option<String>, BoardClientError>> + Send;
+
+  /// check that the backend is reachable and the credentials are accepted
+  ///
+  /// # errors
+  /// - the wrapped backend error if the backend is unreachable or rejects the credentials
+  fn health_check(&self) -> impl Future<Output = Result<(), BoardClientError>> + Send;
+}
+
+/// error type for the [`BoardClient`] trait
+/// - [`BoardClientError::RW`] if there is an error with the read/write api
+/// - [`BoardClientError::Subscription`] if there is an error with the subscription api
+/// - [`BoardClientError::Local`] if there is an error with the local api
+/// - [`BoardClientError::Unsupported`] if the backend does not support the operation
+/// - [`BoardClientError::SubscriptionNotFound`] if the subscription is not available to the installable
+/// - [`BoardClientError::Board`] if the message does not fit the board
+#[derive(Error, Debug)]
+pub enum BoardClientError {
+  /// read/write api error, see wrapped [`crate::rw::RWApiError`] for more details
+  #[cfg(feature = "rw")]
+  #[error("read/write api error: {0}")]
+  RW(#[from] crate::rw::RWApiError),
+  /// subscription api error, see wrapped [`crate::subscription::SubscriptionApiError`] for more details
+  #[cfg(feature = "subscription")]
+  #[error("subscription api error: {0}")]
+  Subscription(#[from] crate::subscription::SubscriptionApiError),
+  /// local api error, see wrapped [`crate::local::LocalApiError`] for more details
+  #[cfg(feature = "local")]
+  #[error("local api error: {0}")]
+  Local(#[from] crate::local::LocalApiError),
+  /// the operation is not supported by the backend, see the wrapped string for the operation
+  #[error("`{0}` is not supported by this api")]
+  Unsupported(&'static str),
+  /// the subscription is not available to the installable, see the wrapped string for the subscription id
+  #[error("subscription `{0}` not found")]
+  SubscriptionNotFound(String),
+  /// the message does not fit the board, see wrapped [`crate::board::BoardError`] for more details
+  #[error("invalid message: {0}")]
+  Board(#[from] crate::board::BoardError),
+}
@@ -3,9 +3,16 @@
 
 pub mod board;
 
+#[cfg(any(feature = "rw", feature = "subscription", feature = "local", doc))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "rw", feature = "subscription", feature = "local"))))]
+pub mod client;
+
 #[cfg(any(feature = "local", doc))]
 #[cfg_attr(docsrs, doc(cfg(feature = "local")))]
 pub mod local;
+#[cfg(any(feature = "render", doc))]
+#[cfg_attr(docsrs, doc(cfg(feature = "render")))]
+pub mod render;
 #[cfg(any(feature = "rw", doc))]
 #[cfg_attr(docsrs, doc(cfg(feature = "rw")))]
 pub mod rw;
@@ -17,7 +24,10 @@ pub mod subscription;
 pub mod vbml;
 
 // reexports
-pub use board::{BoardData, CharacterCode};
+pub use board::{BoardData, BoardModel, CharacterCode};
+#[cfg(any(feature = "rw", feature = "subscription", feature = "local", doc))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "rw", feature = "subscription", feature = "local"))))]
+pub use client::{BoardClient, BoardClientError};
 #[cfg(any(feature = "local", doc))]
 #[cfg_attr(docsrs, doc(cfg(feature = "local")))]
 pub use local::{LocalApiError, LocalConfig};
@@ -26,10 +36,12 @@ pub use local::{LocalApiError, LocalConfig};
 pub use rw::{RWApiError, RWApiReadMessage, RWApiWriteResponse, RWConfig};
 #[cfg(any(feature = "subscription", doc))]
 #[cfg_attr(docsrs, doc(cfg(feature = "subscription")))]
-pub use subscription::{SubscriptionApiError, SubscriptionConfig, SubscriptionMessageResponse, SubscriptionsList};
+pub use subscription::{
+  SubscriptionApiError, SubscriptionBoard, SubscriptionConfig, SubscriptionMessageResponse, SubscriptionsList,
+};
 #[cfg(any(feature = "parser", doc))]
 #[cfg_attr(docsrs, doc(cfg(feature = "parser")))]
-pub use vbml::Vbml;
+pub use vbml::{FlagshipVbml, NoteVbml, Vbml};
 
 /// the main struct for interacting with the Vestaboard api. \
 /// can interact with the r/w api, the subscription api, or the local api.
@@ -39,6 +51,9 @@ pub use vbml::Vbml;
 /// - `ROWS`: the number of rows in the board, defaults to the flagship board size
 /// - `COLS`: the number of columns in the board, defaults to the flagship board size
 ///
+/// to target a specific [`BoardModel`], use the [`FlagshipVestaboard`] or [`NoteVestaboard`] aliases, or
+/// [`ModelVestaboard`] when the model is only known at runtime.
+///
 /// # notes
 /// - when initializing the Vestaboard struct, a type must be provided due to <https://github.com/rust-lang/rust/issues/98931>
 ///
@@ -95,10 +110,12 @@ pub use vbml::Vbml;
 /// ```
 /// async fn get_subscriptions(&self) -> Result<SubscriptionsList, SubscriptionApiError>
 /// async fn write(&self, subscription_id: &str, message: BoardData<ROWS, COLS>) -> Result<SubscriptionMessageResponse, SubscriptionApiError>
+/// fn subscription(&self, subscription_id: impl Into<String>) -> SubscriptionBoard<ROWS, COLS>
 /// ```
 ///
 /// ## types
 /// - [`SubscriptionConfig`] is the config type for the subscription api
+/// - [`SubscriptionBoard`] is a single subscribed Vestaboard that implements [`BoardClient`]
 /// - [`SubscriptionsList`] is the response type for the get_subscriptions method
 /// - [`SubscriptionMessageResponse`] is the response type for the write method
 /// - [`SubscriptionApiError`] is the error enum for the subscription api
@@ -143,6 +160,16 @@ pub use vbml::Vbml;
 ///
 /// <https://docs.vestaboard.com/docs/local-api/introduction>
 ///
+/// # common client
+/// all three apis implement the [`BoardClient`] trait (the subscription api through [`SubscriptionBoard`]),
+/// so services can be written once and the backend chosen through configuration.
+///
+/// ```
+/// async fn read_board(&self) -> Result<BoardData<ROWS, COLS>, BoardClientError>
+/// async fn write_board(&self, message: BoardData<ROWS, COLS>) -> Result<Option<String>, BoardClientError>
+/// async fn health_check(&self) -> Result<(), BoardClientError>
+/// ```
+///
 /// # api docs
 ///
 /// <https://docs.vestaboard.com/>
@@ -154,3 +181,107 @@ pub struct Vestaboard<T, const ROWS: usize = { board::FLAGSHIP_ROWS }, const COL
   #[allow(dead_code)] // subscription api complains but is used for type inference
   config: T,
 }
+
+/// a [`Vestaboard`] client for the flagship Vestaboard (6x22)
+#[cfg(any(feature = "rw", feature = "subscription", feature = "local", doc))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "rw", feature = "subscription", feature = "local"))))]
+pub type FlagshipVestaboard<T> = Vestaboard<T, { board::FLAGSHIP_ROWS }, { board::FLAGSHIP_COLS }>;
+
+/// a [`Vestaboard`] client for the Vestaboard Note (3x15)
+#[cfg(any(feature = "rw", feature = "subscription", feature = "local", doc))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "rw", feature = "subscription", feature = "local"))))]
+pub type NoteVestaboard<T> = Vestaboard<T, { board::NOTE_ROWS }, { board::NOTE_COLS }>;
+
+/// a [`Vestaboard`] client for a [`BoardModel`] chosen at runtime, such as one read from configuration.
+///
+/// the size of a [`Vestaboard`] is part of its type, so this holds a [`FlagshipVestaboard`] or a
+/// [`NoteVestaboard`] for the model and reads and writes [`board::DynBoardData`] instead of [`BoardData`].
+/// the subscription api is not supported, since an installable can be subscribed to boards of any model.
+///
+/// ## new
+/// ```
+/// fn new_rw_api(model: BoardModel, config: RWConfig) -> Self // requires the `rw` feature
+/// fn new_local_api(model: BoardModel, config: LocalConfig) -> Self // requires the `local` feature
+/// ```
+///
+/// ## example
+/// ```
+/// let model: BoardModel = std::env::var("VESTABOARD_MODEL")?.parse()?;
+/// let api = ModelVestaboard::new_rw_api(model, config);
+/// api.write_board(model.blank_board()).await?;
+/// ```
+#[cfg(any(feature = "rw", feature = "local", doc))]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "rw", feature = "local"))))]
+#[derive(Debug, Clone)]
+pub struct ModelVestaboard<T> {
+  model: BoardModel,
+  client: ModelClient<T>,
+}
+
+/// the sized client of a [`ModelVestaboard`]
+#[cfg(any(feature = "rw", feature = "local", doc))]
+#[derive(Debug, Clone)]
+enum ModelClient<T> {
+  Flagship(FlagshipVestaboard<T>),
+  Note(NoteVestaboard<T>),
+}
+
+#[cfg(any(feature = "rw", feature = "local", doc))]
+impl<T> ModelVestaboard<T>
+where
+  FlagshipVestaboard<T>: BoardClient<{ board::FLAGSHIP_ROWS }, { board::FLAGSHIP_COLS }>,
+  NoteVestaboard<T>: BoardClient<{ board::NOTE_ROWS }, { board::NOTE_COLS }>,
+{
+  /// creates a client for `model`, using `flagship` or `note` to create the sized client
+  fn new(
+    model: BoardModel,
+    flagship: impl FnOnce() -> FlagshipVestaboard<T>,
+    note: impl FnOnce() -> NoteVestaboard<T>,
+  ) -> Self {
+    let client = match model {
+      BoardModel::Flagship | BoardModel::FlagshipWhite => ModelClient::Flagship(flagship()),
+      BoardModel::Note | BoardModel::NoteWhite => ModelClient::Note(note()),
+    };
+
+    ModelVestaboard { model, client }
+  }
+
+  /// the model of the Vestaboard
+  pub fn model(&self) -> BoardModel {
+    self.model
+  }
+
+  /// read the message that is currently on the Vestaboard. see [`BoardClient::read_board`]
+  ///
+  /// # errors
+  /// - the wrapped backend error if the request fails
+  pub async fn read_board(&self) -> Result<board::DynBoardData, BoardClientError> {
+    Ok(match &self.client {
+      ModelClient::Flagship(client) => client.read_board().await?.into(),
+      ModelClient::Note(client) => client.read_board().await?.into(),
+    })
+  }
+
+  /// write a message to the Vestaboard. see [`BoardClient::write_board`]
+  ///
+  /// # errors
+  /// - [`BoardClientError::Board`] if the message does not have the dimensions of the model
+  /// - the wrapped backend error if the request fails
+  pub async fn write_board(&self, message: board::DynBoardData) -> Result<Option<String>, BoardClientError> {
+    match &self.client {
+      ModelClient::Flagship(client) => client.write_board(message.try_into()?).await,
+      ModelClient::Note(client) => client.write_board(message.try_into()?).await,
+    }
+  }
+
+  /// check that the backend is reachable and the credentials are accepted. see [`BoardClient::health_check`]
+  ///
+  /// # errors
+  /// - the wrapped backend error if the backend is unreachable or rejects the credentials
+  pub async fn health_check(&self) -> Result<(), BoardClientError> {
+    match &self.client {
+      ModelClient::Flagship(client) => client.health_check().await,
+      ModelClient::Note(client) => client.health_check().await,
+    }
+  }
+}
@@ -49,7 +49,11 @@
 use serde::Deserialize;
 use thiserror::Error;
 
-use crate::{board::BoardData, Vestaboard};
+use crate::{
+  board::{BoardData, BoardModel},
+  client::{BoardClient, BoardClientError},
+  ModelVestaboard, Vestaboard,
+};
 
 const LOCAL_ENABLEMENT_TOKEN_HEADER: &str = "X-Vestaboard-Local-Api-Enablement-Token";
 const LOCAL_API_KEY_HEADER: &str = "X-Vestaboard-Local-Api-Key";
@@ -248,6 +252,41 @@ impl<const ROWS: usize, const COLS: usize> Vestaboard<LocalConfig, ROWS, COLS> {
   }
 }
 
+impl<const ROWS: usize, const COLS: usize> BoardClient<ROWS, COLS> for Vestaboard<LocalConfig, ROWS, COLS> {
+  /// read the current message on the Vestaboard. see [`Vestaboard::read`]
+  async fn read_board(&self) -> Result<BoardData<ROWS, COLS>, BoardClientError> {
+    Ok(self.read().await?)
+  }
+
+  /// write a message to the Vestaboard. the local api does not return a message id. see [`Vestaboard::write`]
+  async fn write_board(&self, message: BoardData<ROWS, COLS>) -> Result<Option<String>, BoardClientError> {
+    self.write(message).await?;
+    Ok(None)
+  }
+
+  /// reads the current message to check that the Vestaboard is reachable and the api key is accepted
+  async fn health_check(&self) -> Result<(), BoardClientError> {
+    self.read().await?;
+    Ok(())
+  }
+}
+
+impl ModelVestaboard<LocalConfig> {
+  /// create a new [`ModelVestaboard`] instance for a local Vestaboard of the given model.
+  /// see [`Vestaboard::new_local_api`]
+  ///
+  /// # panics
+  /// panics if the `api_key` cannot be parsed
+  pub fn new_local_api(model: BoardModel, config: LocalConfig) -> Self {
+    let note = config.clone();
+    ModelVestaboard::new(
+      model,
+      || Vestaboard::new_local_api(config),
+      || Vestaboard::new_local_api(note),
+    )
+  }
+}
+
 /// response type for the local api enablement request
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -0,0 +1,284 @@
+//! renders boards to SVG and PNG images with a split-flap look (requires the `render` feature)
+//!
+//! every cell is drawn as a flap with a slightly lighter top half and a split line across the
+//! middle. characters are drawn with a built-in 5x7 bitmap glyph set, so no fonts are needed, and
+//! the color codes (63-71) are drawn as solid tiles. sizes and colors are configured with
+//! [`RenderOptions`].
+//!
+//! # example
+//! ```
+//! let board: BoardData = BoardData::centered("hello world");
+//! let options = RenderOptions::default().cell_size(40, 60).bezel(32);
+//!
+//! std::fs::write("preview.svg", board.to_svg(&options))?;
+//! std::fs::write("preview.png", board.to_png(&options)?)?;
+//! ```
+
+mod glyph;
+mod raster;
+mod svg;
+
+use thiserror::Error;
+
+use crate::board::{BoardData, BoardEdition, CharacterCode, DynBoardData};
+
+pub use crate::board::{tile_color, Rgb};
+pub use raster::RgbImage;
+
+/// the largest number of pixels a rendered image may have
+pub const MAX_PIXELS: u64 = 1 << 26;
+
+/// lightens or darkens a color by adding `amount` to every channel
+fn shade(Rgb(r, g, b): Rgb, amount: i16) -> Rgb {
+  let channel = |value: u8| (value as i16 + amount).clamp(0, u8::MAX as i16) as u8;
+  Rgb(channel(r), channel(g), channel(b))
+}
+
+/// options for rendering a board. sizes are in pixels.
+///
+/// the defaults look like a black edition flagship Vestaboard, with 30x45 cells. use
+/// [`RenderOptions::edition`] to render a white edition board.
+#[derive(Debug, Clone)]
+pub struct RenderOptions {
+  /// the width of a cell
+  pub cell_width: u32,
+  /// the height of a cell
+  pub cell_height: u32,
+  /// the space between cells
+  pub gap: u32,
+  /// the width of the frame around the board
+  pub bezel: u32,
+  /// the color of the frame and the space between cells
+  pub bezel_color: Rgb,
+  /// the color of a blank flap. the top half of the flap is drawn slightly lighter
+  pub flap_color: Rgb,
+  /// the color of the characters
+  pub text_color: Rgb,
+  /// whether to draw the split line across the middle of every cell
+  pub split: bool,
+  /// the edition whose color tiles are drawn, which decides the color of [`CharacterCode::Filled`]
+  pub edition: BoardEdition,
+}
+
+impl Default for RenderOptions {
+  fn default() -> Self {
+    let edition = BoardEdition::default();
+    RenderOptions {
+      cell_width: 30,
+      cell_height: 45,
+      gap: 4,
+      bezel: 24,
+      bezel_color: edition.bezel_color(),
+      flap_color: edition.flap_color(),
+      text_color: edition.text_color(),
+      split: true,
+      edition,
+    }
+  }
+}
+
+impl RenderOptions {
+  /// sets the width and height of a cell
+  pub fn cell_size(mut self, width: u32, height: u32) -> Self {
+    self.cell_width = width;
+    self.cell_height = height;
+    self
+  }
+
+  /// sets the space between cells
+  pub fn gap(mut self, gap: u32) -> Self {
+    self.gap = gap;
+    self
+  }
+
+  /// sets the width of the frame around the board
+  pub fn bezel(mut self, bezel: u32) -> Self {
+    self.bezel = bezel;
+    self
+  }
+
+  /// sets the color of the frame and the space between cells
+  pub fn bezel_color(mut self, color: Rgb) -> Self {
+    self.bezel_color = color;
+    self
+  }
+
+  /// sets the color of a blank flap
+  pub fn flap_color(mut self, color: Rgb) -> Self {
+    self.flap_color = color;
+    self
+  }
+
+  /// sets the color of the characters
+  pub fn text_color(mut self, color: Rgb) -> Self {
+    self.text_color = color;
+    self
+  }
+
+  /// sets whether to draw the split line across the middle of every cell
+  pub fn split(mut self, split: bool) -> Self {
+    self.split = split;
+    self
+  }
+
+  /// sets the edition of the board, along with the bezel, flap, and text colors of the edition
+  pub fn edition(mut self, edition: BoardEdition) -> Self {
+    self.edition = edition;
+    self.bezel_color = edition.bezel_color();
+    self.flap_color = edition.flap_color();
+    self.text_color = edition.text_color();
+    self
+  }
+}
+
+/// errors that can occur when rendering a board
+/// - [`RenderError::TooLarge`] if the image would have more than [`MAX_PIXELS`] pixels
+/// - [`RenderError::Png`] if there is an error encoding the PNG
+#[derive(Error, Debug)]
+pub enum RenderError {
+  /// the rendered image would be too large
+  #[error("rendered image is too large: {width}x{height}")]
+  TooLarge { width: u64, height: u64 },
+  /// png encoding error, see wrapped [`png::EncodingError`] for more details
+  #[error("failed to encode png: {0}")]
+  Png(#[from] png::EncodingError),
+}
+
+/// a solid rectangle of the rendered image. both backends draw the same fills in order.
+#[derive(Debug, Clone, Copy)]
+struct Fill {
+  x: u64,
+  y: u64,
+  width: u64,
+  height: u64,
+  color: Rgb,
+}
+
+/// the layout of a rendered board
+struct Scene {
+  width: u64,
+  height: u64,
+  background: Rgb,
+  fills: Vec<Fill>,
+}
+
+impl Scene {
+  /// lays out a `rows`x`cols` board from an iterator over its rows of character codes
+  fn new<'a>(rows: usize, cols: usize, cells: impl Iterator<Item = &'a [u8]>, options: &RenderOptions) -> Self {
+    let cell_width = options.cell_width as u64;
+    let cell_height = options.cell_height as u64;
+    let gap = options.gap as u64;
+    let bezel = options.bezel as u64;
+    let extent = |cells: usize, size: u64| {
+      let cells = cells as u64;
+      2 * bezel + cells * size + cells.saturating_sub(1) * gap
+    };
+
+    let width = extent(cols, cell_width);
+    let height = extent(rows, cell_height);
+
+    // the glyph is scaled by whole pixels to stay crisp, leaving a margin around it
+    let dot = (cell_width * 3 / 4 / glyph::GLYPH_WIDTH).min(cell_height * 3 / 5 / glyph::GLYPH_HEIGHT);
+    let split = (cell_height / 24).max(1);
+    let top_color = shade(options.flap_color, 10);
+
+    let mut fills = Vec::new();
+    for (row, codes) in cells.take(rows).enumerate() {
+      for (col, code) in codes.iter().take(cols).enumerate() {
+        let x = bezel + col as u64 * (cell_width + gap);
+        let y = bezel + row as u64 * (cell_height + gap);
+        let mut fill = |x, y, width, height, color| {
+          fills.push(Fill {
+            x,
+            y,
+            width,
+            height,
+            color,
+          })
+        };
+
+        match options.edition.tile_color(CharacterCode::from_u8_lossy(*code)) {
+          Some(color) => fill(x, y, cell_width, cell_height, color),
+          None => {
+            fill(x, y, cell_width, cell_height, options.flap_color);
+            fill(x, y, cell_width, cell_height / 2, top_color);
+
+            if let Some(glyph) = glyph::glyph(*code).filter(|_| dot > 0) {
+              let glyph_x = x + (cell_width - glyph::GLYPH_WIDTH * dot) / 2;
+              let glyph_y = y + (cell_height - glyph::GLYPH_HEIGHT * dot) / 2;
+
+              for (dot_row, dot_col, len) in glyph::runs(&glyph) {
+                fill(
+                  glyph_x + dot_col * dot,
+                  glyph_y + dot_row * dot,
+                  len * dot,
+                  dot,
+                  options.text_color,
+                );
+              }
+            }
+          }
+        }
+
+        if options.split && cell_height > split {
+          fill(x, y + (cell_height - split) / 2, cell_width, split, options.bezel_color);
+        }
+      }
+    }
+
+    Scene {
+      width,
+      height,
+      background: options.bezel_color,
+      fills,
+    }
+  }
+}
+
+impl<const ROWS: usize, const COLS: usize> BoardData<ROWS, COLS> {
+  /// renders the board to an SVG document. see [`RenderOptions`]
+  pub fn to_svg(&self, options: &RenderOptions) -> String {
+    svg::render(&Scene::new(ROWS, COLS, self.0.iter().map(|row| &row[..]), options))
+  }
+
+  /// renders the board to an [`RgbImage`]. see [`RenderOptions`]
+  ///
+  /// # errors
+  /// - [`RenderError::TooLarge`] if the image would have more than [`MAX_PIXELS`] pixels
+  pub fn to_image(&self, options: &RenderOptions) -> Result<RgbImage, RenderError> {
+    raster::render(&Scene::new(ROWS, COLS, self.0.iter().map(|row| &row[..]), options))
+  }
+
+  /// renders the board to a PNG image. see [`RenderOptions`]
+  ///
+  /// # errors
+  /// - [`RenderError::TooLarge`] if the image would have more than [`MAX_PIXELS`] pixels
+  /// - [`RenderError::Png`] if there is an error encoding the PNG
+  pub fn to_png(&self, options: &RenderOptions) -> Result<Vec<u8>, RenderError> {
+    self.to_image(options)?.to_png()
+  }
+}
+
+impl DynBoardData {
+  /// renders the board to an SVG document. see [`RenderOptions`]
+  pub fn to_svg(&self, options: &RenderOptions) -> String {
+    svg::render(&Scene::new(self.rows(), self.cols(), self.iter_rows(), options))
+  }
+
+  /// renders the board to an [`RgbImage`]. see [`RenderOptions`]
+  ///
+  /// # errors
+  /// - [`RenderError::TooLarge`] if the image would have more than [`MAX_PIXELS`] pixels
+  pub fn to_image(&self, options: &RenderOptions) -> Result<RgbImage, RenderError> {
+    raster::render(&Scene::new(self.rows(), self.cols(), self.iter_rows(), options))
+  }
+
+  /// renders the board to a PNG image. see [`RenderOptions`]
+  ///
+  /// # errors
+  /// - [`RenderError::TooLarge`] if the image would have more than [`MAX_PIXELS`] pixels
+  /// - [`RenderError::Png`] if there is an error encoding the PNG
+  pub fn to_png(&self, options: &RenderOptions) -> Result<Vec<u8>, RenderError> {
+    self.to_image(options)?.to_png()
+  }
+}
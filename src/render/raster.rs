@@ -0,0 +1,103 @@
+use super::{RenderError, Rgb, Scene, MAX_PIXELS};
+
+/// a rendered image with 8-bit rgb pixels, row by row from the top left
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RgbImage {
+  width: u32,
+  height: u32,
+  pixels: Vec<u8>,
+}
+
+impl RgbImage {
+  /// the width of the image in pixels
+  pub fn width(&self) -> u32 {
+    self.width
+  }
+
+  /// the height of the image in pixels
+  pub fn height(&self) -> u32 {
+    self.height
+  }
+
+  /// the raw pixels of the image, three bytes per pixel
+  pub fn pixels(&self) -> &[u8] {
+    &self.pixels
+  }
+
+  /// gets the color of the pixel at `x`, `y`, or `None` if it is out of bounds
+  pub fn pixel(&self, x: u32, y: u32) -> Option<Rgb> {
+    if x >= self.width || y >= self.height {
+      return None;
+    }
+
+    let index = (y as usize * self.width as usize + x as usize) * 3;
+    Some(Rgb(self.pixels[index], self.pixels[index + 1], self.pixels[index + 2]))
+  }
+
+  /// encodes the image as a PNG
+  ///
+  /// # errors
+  /// - [`RenderError::Png`] if there is an error encoding the PNG
+  pub fn to_png(&self) -> Result<Vec<u8>, RenderError> {
+    let mut png = Vec::new();
+    self.write_png(&mut png)?;
+    Ok(png)
+  }
+
+  /// encodes the image as a PNG into a writer
+  ///
+  /// # errors
+  /// - [`RenderError::Png`] if there is an error encoding the PNG or writing to the writer
+  pub fn write_png(&self, writer: impl std::io::Write) -> Result<(), RenderError> {
+    let mut encoder = png::Encoder::new(writer, self.width, self.height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&self.pixels)?;
+    writer.finish()?;
+    Ok(())
+  }
+}
+
+/// rasterizes a scene
+///
+/// # errors
+/// - [`RenderError::TooLarge`] if the image would have more than [`MAX_PIXELS`] pixels
+pub(super) fn render(scene: &Scene) -> Result<RgbImage, RenderError> {
+  let (Ok(width), Ok(height)) = (u32::try_from(scene.width), u32::try_from(scene.height)) else {
+    return Err(RenderError::TooLarge {
+      width: scene.width,
+      height: scene.height,
+    });
+  };
+  if scene.width.saturating_mul(scene.height) > MAX_PIXELS {
+    return Err(RenderError::TooLarge {
+      width: scene.width,
+      height: scene.height,
+    });
+  }
+
+  let Rgb(r, g, b) = scene.background;
+  let mut image = RgbImage {
+    width,
+    height,
+    pixels: [r, g, b].repeat(width as usize * height as usize),
+  };
+
+  for fill in &scene.fills {
+    let Rgb(r, g, b) = fill.color;
+    let right = (fill.x + fill.width).min(scene.width);
+    let bottom = (fill.y + fill.height).min(scene.height);
+
+    for y in fill.y..bottom {
+      let start = (y * scene.width + fill.x.min(right)) as usize * 3;
+      let end = (y * scene.width + right) as usize * 3;
+      for pixel in image.pixels[start..end].chunks_exact_mut(3) {
+        pixel.copy_from_slice(&[r, g, b]);
+      }
+    }
+  }
+
+  Ok(image)
+}
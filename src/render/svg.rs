@@ -0,0 +1,32 @@
+use std::fmt::Write;
+
+use super::Scene;
+
+/// writes a scene as an SVG document
+pub(super) fn render(scene: &Scene) -> String {
+  let mut svg = String::new();
+
+  // writing to a string cannot fail
+  let _ = writeln!(
+    svg,
+    r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" shape-rendering="crispEdges">"#,
+    w = scene.width,
+    h = scene.height,
+  );
+  let _ = writeln!(
+    svg,
+    r#"<rect width="{}" height="{}" fill="{}"/>"#,
+    scene.width, scene.height, scene.background
+  );
+
+  for fill in &scene.fills {
+    let _ = writeln!(
+      svg,
+      r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+      fill.x, fill.y, fill.width, fill.height, fill.color
+    );
+  }
+
+  svg.push_str("</svg>\n");
+  svg
+}
@@ -38,7 +38,10 @@
 use serde::Deserialize;
 use thiserror::Error;
 
-use crate::{BoardData, Vestaboard};
+use crate::{
+  client::{BoardClient, BoardClientError},
+  BoardData, BoardModel, ModelVestaboard, Vestaboard,
+};
 
 const RW_API_URI: &str = "https://rw.vestaboard.com/";
 const RW_API_HEADER: &str = "X-Vestaboard-Read-Write-Key";
@@ -140,6 +143,37 @@ impl<const ROWS: usize, const COLS: usize> Vestaboard<RWConfig, ROWS, COLS> {
   }
 }
 
+impl<const ROWS: usize, const COLS: usize> BoardClient<ROWS, COLS> for Vestaboard<RWConfig, ROWS, COLS> {
+  /// read the current message on the Vestaboard. see [`Vestaboard::read`]
+  async fn read_board(&self) -> Result<BoardData<ROWS, COLS>, BoardClientError> {
+    Ok(self.read().await?.board)
+  }
+
+  /// write a message to the Vestaboard. see [`Vestaboard::write`]
+  async fn write_board(&self, message: BoardData<ROWS, COLS>) -> Result<Option<String>, BoardClientError> {
+    Ok(Some(self.write(message).await?.id))
+  }
+
+  /// reads the current message to check that the read/write key is accepted
+  async fn health_check(&self) -> Result<(), BoardClientError> {
+    self.read().await?;
+    Ok(())
+  }
+}
+
+impl ModelVestaboard<RWConfig> {
+  /// create a new [`ModelVestaboard`] instance for a read/write api enabled Vestaboard of the given model.
+  /// see [`Vestaboard::new_rw_api`]
+  pub fn new_rw_api(model: BoardModel, config: RWConfig) -> Self {
+    let note = config.clone();
+    ModelVestaboard::new(
+      model,
+      || Vestaboard::new_rw_api(config),
+      || Vestaboard::new_rw_api(note),
+    )
+  }
+}
+
 /// the current message on the Vestaboard
 pub struct RWApiReadMessage<const ROWS: usize, const COLS: usize> {
   /// a string representation of a [`crate::board::Board<ROWS, COLS>`]
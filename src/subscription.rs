@@ -19,10 +19,12 @@
 //! ```
 //! async fn get_subscriptions(&self) -> Result<SubscriptionsList, SubscriptionApiError>
 //! async fn write(&self, subscription_id: &str, message: BoardData<ROWS, COLS>) -> Result<SubscriptionMessageResponse, SubscriptionApiError>
+//! fn subscription(&self, subscription_id: impl Into<String>) -> SubscriptionBoard<ROWS, COLS>
 //! ```
 //!
 //! ## types
 //! - [`SubscriptionConfig`] is the config type for the subscription api
+//! - [`SubscriptionBoard`] is a single subscribed Vestaboard that implements [`BoardClient`]
 //! - [`SubscriptionsList`] is the response type for the get_subscriptions method
 //! - [`SubscriptionMessageResponse`] is the response type for the write method
 //! - [`SubscriptionApiError`] is the error enum for the subscription api
@@ -43,7 +45,10 @@
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::{BoardData, Vestaboard};
+use crate::{
+  client::{BoardClient, BoardClientError},
+  BoardData, Vestaboard,
+};
 
 const SUBSCRIPTION_API_KEY_HEADER: &str = "X-Vestaboard-Api-Key";
 const SUBSCRIPTION_API_SECRET_HEADER: &str = "X-Vestaboard-Api-Secret";
@@ -148,7 +153,7 @@ impl<const ROWS: usize, const COLS: usize> Vestaboard<SubscriptionConfig, ROWS,
 
     let res = self
       .client
-      .post(&format!(
+      .post(format!(
         "https://subscriptions.vestaboard.com/subscriptions/{}/message",
         subscription_id
       ))
@@ -162,6 +167,61 @@ impl<const ROWS: usize, const COLS: usize> Vestaboard<SubscriptionConfig, ROWS,
 
     Ok(res.json::<SubscriptionMessageResponse>().await?)
   }
+
+  /// get a handle to a single subscribed Vestaboard. the handle implements [`BoardClient`]
+  ///
+  /// # args
+  /// - `subscription_id`: the id of the subscription that the handle will send messages to
+  ///
+  /// # returns
+  /// a [`SubscriptionBoard`] for the subscription
+  pub fn subscription(&self, subscription_id: impl Into<String>) -> SubscriptionBoard<ROWS, COLS> {
+    SubscriptionBoard {
+      api: self.clone(),
+      subscription_id: subscription_id.into(),
+    }
+  }
+}
+
+/// a single Vestaboard managed by the subscription api. created with [`Vestaboard::subscription`]
+///
+/// # type parameters
+/// - `ROWS`: the number of rows in the board
+/// - `COLS`: the number of columns in the board
+#[derive(Debug, Clone)]
+pub struct SubscriptionBoard<const ROWS: usize, const COLS: usize> {
+  api: Vestaboard<SubscriptionConfig, ROWS, COLS>,
+  subscription_id: String,
+}
+
+impl<const ROWS: usize, const COLS: usize> SubscriptionBoard<ROWS, COLS> {
+  /// the id of the subscription that this handle sends messages to
+  pub fn subscription_id(&self) -> &str {
+    &self.subscription_id
+  }
+}
+
+impl<const ROWS: usize, const COLS: usize> BoardClient<ROWS, COLS> for SubscriptionBoard<ROWS, COLS> {
+  /// the subscription api cannot read a board, so this always returns [`BoardClientError::Unsupported`]
+  async fn read_board(&self) -> Result<BoardData<ROWS, COLS>, BoardClientError> {
+    Err(BoardClientError::Unsupported("read"))
+  }
+
+  /// send a message to the subscribed Vestaboard. see [`Vestaboard::write`]
+  async fn write_board(&self, message: BoardData<ROWS, COLS>) -> Result<Option<String>, BoardClientError> {
+    Ok(Some(self.api.write(&self.subscription_id, message).await?.id))
+  }
+
+  /// lists the subscriptions of the installable and checks that this subscription is among them
+  async fn health_check(&self) -> Result<(), BoardClientError> {
+    let subscriptions = self.api.get_subscriptions().await?;
+
+    if subscriptions.0.iter().any(|s| s.id == self.subscription_id) {
+      Ok(())
+    } else {
+      Err(BoardClientError::SubscriptionNotFound(self.subscription_id.clone()))
+    }
+  }
 }
 
 /// message to send to a subscribed Vestaboard
@@ -0,0 +1,56 @@
+use crate::board::Replacement;
+
+/// why a [`VbmlDiagnostic`] was reported
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VbmlDiagnosticKind {
+  /// the text of a template component did not fit its height and the rest was dropped
+  TruncatedText,
+  /// cells of the component were outside the board and were not drawn, see `count` for how many
+  ClippedCells { count: usize },
+  /// the component was drawn over cells of an earlier template component, see `other` for the index of
+  /// that component and `count` for how many cells
+  Overlap { other: usize, count: usize },
+  /// the template uses a prop that is not in the props of the VBML and was left empty
+  UnknownProp { name: String },
+  /// a character code that cannot be displayed, such as `{43}`, was replaced with a blank
+  InvalidCode { code: String },
+  /// a character that is not on the board was substituted or dropped
+  Replaced(Replacement),
+}
+
+/// a problem found while parsing VBML that changed how the board looks, found by
+/// [`super::VbmlParser::parse_with_diagnostics`]
+///
+/// the parse still succeeds, the same as the JS library, but the author probably did not mean for it to
+/// happen. the position is the top left corner of the component on the board, which may be outside of it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VbmlDiagnostic {
+  /// the index of the component in the `components` of the VBML
+  pub component: usize,
+  /// the row of the component on the board
+  pub row: usize,
+  /// the column of the component on the board
+  pub col: usize,
+  /// what happened
+  pub kind: VbmlDiagnosticKind,
+}
+
+impl std::fmt::Display for VbmlDiagnostic {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "component {} at ({}, {}): ", self.component, self.row, self.col)?;
+
+    match &self.kind {
+      VbmlDiagnosticKind::TruncatedText => write!(f, "text does not fit the component and was cut off"),
+      VbmlDiagnosticKind::ClippedCells { count } => write!(f, "{count} cells are outside the board"),
+      VbmlDiagnosticKind::Overlap { other, count } => write!(f, "covers {count} cells of component {other}"),
+      VbmlDiagnosticKind::UnknownProp { name } => write!(f, "prop {name:?} is not defined"),
+      VbmlDiagnosticKind::InvalidCode { code } => write!(f, "character code {code} was replaced with a blank"),
+      VbmlDiagnosticKind::Replaced(Replacement::Substituted {
+        original, replacement, ..
+      }) => write!(f, "{original:?} was replaced with {replacement:?}"),
+      VbmlDiagnosticKind::Replaced(Replacement::Dropped { original, .. }) => {
+        write!(f, "{original:?} is not on the board and was dropped")
+      }
+    }
+  }
+}
@@ -1,34 +1,19 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use crate::board::{char::CharacterCode, BoardData};
-
-use super::VbmlError;
+use super::MAX_SIZE;
+use crate::board::{char::CharacterCode, layout, transliterate, Align, DynBoardData, Justify};
 
 lazy_static::lazy_static! {
   pub static ref PROPS_REGEX: regex::Regex = regex::Regex::new(r#"\{(\d+)\}"#).expect("failed to create regex");
   pub static ref TEMPLATE_REGEX: regex::Regex = regex::Regex::new(r#"\{(\d+)\}|\{\{([A-Za-z0-9]+)\}\}"#).expect("failed to create regex");
 }
 
-/// enum representing the horizontal justification of a component
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum Justify {
-  Center,
-  Left,
-  Right,
-  Justified,
-}
-
-/// enum representing the vertical alignment of a component
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum Align {
-  Center,
-  Top,
-  Bottom,
-  Justified,
-  Absolute,
+/// the character code of a `{code}` template, or [`CharacterCode::Blank`] if it cannot be displayed
+fn code_from_template(code: &str) -> CharacterCode {
+  code
+    .parse::<u8>()
+    .map_or(CharacterCode::Blank, CharacterCode::from_u8_lossy)
 }
 
 /// struct representing the absolute position of a component
@@ -55,18 +40,17 @@ pub struct ComponentStyle {
   pub absolute_position: Option<AbsolutePosition>,
 }
 
-/// # NOTE: VALUE IS IGNORED IN CURRENT IMPLEMENTATION
-///
-/// struct representing the style of a VBML component
+/// struct representing the style of a VBML document
 ///
-/// in the future it could be used to change the size of the
-/// resulting [`BoardData`] after parsing, but for now
-/// the value of [`VbmlStyle`] is ignored. to change the size of
-/// the resulting [`BoardData`], change the `ROWS` and `COLS` const generics.
+/// sets the size of the board produced by [`super::Vbml::parse_dyn`] as a
+/// [`crate::board::DynBoardData`]. [`super::Vbml::parse`] ignores the value of
+/// [`VbmlStyle`] and always produces a `ROWS`x`COLS` [`crate::board::BoardData`].
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VbmlStyle {
+  /// optional number of rows of the board
   pub height: Option<u32>,
+  /// optional number of columns of the board
   pub width: Option<u32>,
 }
 
@@ -77,7 +61,8 @@ pub struct VbmlProps(HashMap<String, String>);
 
 impl VbmlProps {
   /// replaces any templates in the prop values with character values
-  /// derived from [`CharacterCode`]
+  /// derived from [`CharacterCode`]. codes that cannot be displayed, such as `{43}` or `{999}`, are
+  /// replaced with blanks
   pub fn replace_template(&self) -> HashMap<String, String> {
     self
       .0
@@ -87,8 +72,7 @@ impl VbmlProps {
           k.to_string(),
           PROPS_REGEX
             .replace_all(v, |caps: &regex::Captures| {
-              let char_code = caps.get(1).unwrap().as_str().parse::<u8>().unwrap();
-              let char: char = CharacterCode::from(char_code).into();
+              let char: char = code_from_template(&caps[1]).into();
 
               format!("{}", char)
             })
@@ -106,15 +90,16 @@ impl VbmlProps {
 /// the documentation says this is useful for setting backgrounds.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct VbmlRawComponent<const ROWS: usize, const COLS: usize> {
+pub struct VbmlRawComponent {
   /// optional style of the component as [`ComponentStyle`]. if not provided,
   /// a style component filled with None values will be used.
   #[serde(default)]
   pub style: ComponentStyle,
-  /// the raw character codes of the component in format [`BoardData<ROWS, COLS>`].
+  /// the raw character codes of the component as a [`DynBoardData`]. they are placed in the
+  /// top left corner of the board, and any cells they do not cover are left blank.
   ///
-  /// can be coerced from a raw [`crate::board::Board`] type.
-  pub raw_characters: BoardData<ROWS, COLS>,
+  /// can be coerced from a [`crate::board::BoardData`] or a raw [`crate::board::Board`] type.
+  pub raw_characters: DynBoardData,
 }
 
 /// struct representing a VBML "template" component.
@@ -135,17 +120,50 @@ pub struct VbmlTemplateComponent {
 }
 
 impl VbmlTemplateComponent {
-  /// renders the template string of the component using the [`VbmlProps`] object.
-  ///
-  /// returns the rendered string or a [`VbmlError::Regex`] if there is an error.
-  pub fn render(&self, props: Option<&HashMap<String, String>>) -> Result<String, VbmlError> {
+  /// finds every `{code}` in the template, and in the prop values used by the template, that is not a
+  /// character code that can be displayed in a cell
+  pub(crate) fn invalid_codes(&self, props: Option<&VbmlProps>) -> Vec<String> {
+    let used_props = TEMPLATE_REGEX
+      .captures_iter(&self.template)
+      .filter_map(|caps| caps.get(2))
+      .filter_map(|name| props.and_then(|props| props.0.get(name.as_str())));
+
+    std::iter::once(&self.template)
+      .chain(used_props)
+      .flat_map(|text| {
+        PROPS_REGEX
+          .captures_iter(text)
+          .map(|caps| caps[1].to_string())
+          .filter(|code| !code.parse::<u8>().is_ok_and(CharacterCode::is_cell_code))
+      })
+      .collect()
+  }
+
+  /// finds the names of the props used by the template that are not in `props`, without repeats
+  pub(crate) fn unknown_props(&self, props: Option<&VbmlProps>) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for name in TEMPLATE_REGEX
+      .captures_iter(&self.template)
+      .filter_map(|caps| caps.get(2))
+    {
+      let known = props.is_some_and(|props| props.0.contains_key(name.as_str()));
+      if !known && !names.iter().any(|unknown| unknown == name.as_str()) {
+        names.push(name.as_str().to_string());
+      }
+    }
+
+    names
+  }
+
+  /// renders the template string of the component using the [`VbmlProps`] object. codes that cannot be
+  /// displayed are replaced with blanks, and props that are not defined are left empty.
+  pub fn render(&self, props: Option<&HashMap<String, String>>) -> String {
     // tracing::trace!("template: {:?}; props: {:?}", &self.template, props);
 
-    let string = TEMPLATE_REGEX
+    TEMPLATE_REGEX
       .replace_all(&self.template, |caps: &regex::Captures| {
         if let Some(char_code) = caps.get(1) {
-          let char_code = char_code.as_str().parse::<u8>().unwrap();
-          let char: char = CharacterCode::from(char_code).into();
+          let char: char = code_from_template(char_code.as_str()).into();
 
           return format!("{}", char);
         }
@@ -160,9 +178,7 @@ impl VbmlTemplateComponent {
 
         "".to_string()
       })
-      .to_string();
-
-    Ok(string)
+      .to_string()
   }
 }
 
@@ -174,7 +190,7 @@ impl VbmlTemplateComponent {
 #[serde(rename_all = "camelCase", untagged)]
 pub enum VbmlComponent<const ROWS: usize, const COLS: usize> {
   /// a component with `raw_characters` and no `template`
-  Raw(VbmlRawComponent<ROWS, COLS>),
+  Raw(VbmlRawComponent),
   /// a component with `template` and no `raw_characters`
   Template(VbmlTemplateComponent),
 }
@@ -187,6 +203,8 @@ impl<const ROWS: usize, const COLS: usize> VbmlComponent<ROWS, COLS> {
   /// if the component is a raw component, the return will have just the height and width, and no character codes.
   /// the character codes of a raw component must be accessed separately.
   ///
+  /// a height or width in the style that is larger than [`MAX_SIZE`] is treated as [`MAX_SIZE`].
+  ///
   /// # returns
   /// - the number of rows in the component: `usize`
   /// - the widest row in the component: `usize`
@@ -197,95 +215,37 @@ impl<const ROWS: usize, const COLS: usize> VbmlComponent<ROWS, COLS> {
     &self,
     props: Option<&HashMap<String, String>>,
   ) -> (usize, usize, Option<[Vec<CharacterCode>; ROWS]>) {
+    let (height, widest_width, content) = self.word_rows(props, ROWS, COLS);
+    let content = content.map(|rows| {
+      let mut rows = rows.into_iter();
+      std::array::from_fn(|_| rows.next().unwrap_or_default())
+    });
+
+    (height, widest_width, content)
+  }
+
+  /// same as [`VbmlComponent::get_word_rows`], but for a board of `rows`x`cols` chosen at runtime.
+  /// components without a height or width fill the board in that dimension.
+  pub(crate) fn word_rows(
+    &self,
+    props: Option<&HashMap<String, String>>,
+    rows: usize,
+    cols: usize,
+  ) -> (usize, usize, Option<Vec<Vec<CharacterCode>>>) {
     match self {
       VbmlComponent::Template(template) => {
         let style = self.get_style();
 
-        let comp_height = style.height.unwrap_or(ROWS as u32) as usize;
-        let comp_width = style.width.unwrap_or(COLS as u32) as usize;
-
-        let mut text = template.render(props).unwrap_or(String::new());
-
-        const ARRAY_REPEAT_VALUE: Vec<CharacterCode> = Vec::new();
-        let mut text_mapping: [Vec<CharacterCode>; ROWS] = [ARRAY_REPEAT_VALUE; ROWS];
-
-        if text.is_empty() {
-          (0..comp_height).for_each(|i| (0..comp_width).for_each(|_| text_mapping[i].push(CharacterCode::Blank)));
-          return (comp_height, comp_width, Some(text_mapping));
-        }
-
-        let mut remove_space = true;
-        text = text
-          .chars()
-          .rev()
-          .filter(|c| {
-            if remove_space && *c == ' ' {
-              return false;
-            }
-
-            remove_space = *c == '\n';
-            true
-          })
-          .collect();
-        text = text.chars().rev().collect();
-        tracing::trace!("text: {:?}", text);
-
-        let mut words = text.split_inclusive('\n').flat_map(|s| s.split(' ')).peekable();
-        tracing::trace!("words: {:?}", words.clone().collect::<Vec<_>>());
-
-        let mut row: usize = 0;
-        let mut col: usize = 0;
-        while let Some(word) = words.next() {
-          let next_word = words.peek();
-          tracing::trace!("word: {word}; next_word: {:?}; col: {col}; row: {row}", next_word);
-
-          if word.len() > (comp_width - col) && word.len() < comp_width && word.chars().nth(0).unwrap_or(' ') != '\n' {
-            col = 0;
-            row += 1;
-          }
-
-          let mut ended_on_newline = false;
-          for char in word.chars().map(CharacterCode::from) {
-            tracing::trace!("char: {char}; col: {col}; row: {row}");
-            if col >= comp_width {
-              col = 0;
-              row += 1;
-
-              if char == CharacterCode::Newline {
-                ended_on_newline = true;
-                continue;
-              }
-            }
-
-            if char == CharacterCode::Newline {
-              col = 0;
-              row += 1;
-              ended_on_newline = true;
-              continue;
-            }
-
-            if row >= comp_height {
-              // panic!("row out of bounds");
-              break;
-            }
-
-            text_mapping[row].push(char);
-            col += 1;
-          }
-
-          if let Some(next_word) = next_word {
-            if col < comp_width && next_word.len() < comp_width - col && !ended_on_newline {
-              text_mapping[row].push(CharacterCode::Blank);
-              col += 1;
-            }
-          }
-        }
+        // the parser rejects these sizes, but nothing stops a component from being laid out on its own
+        let comp_height = style.height.map_or(rows, |height| (height as usize).min(MAX_SIZE));
+        let comp_width = style.width.map_or(cols, |width| (width as usize).min(MAX_SIZE));
 
-        let text_widest_width = text_mapping.iter().map(|row| row.len()).max().unwrap_or(0);
+        let text = transliterate(&template.render(props)).text;
+        let (content_height, content_widest_width, content) = layout::wrap_words(&text, comp_height, comp_width, rows);
 
-        (row + 1, text_widest_width, Some(text_mapping))
+        (content_height, content_widest_width, Some(content))
       }
-      VbmlComponent::Raw(_) => (ROWS, COLS, None),
+      VbmlComponent::Raw(_) => (rows, cols, None),
     }
   }
 
@@ -6,7 +6,8 @@
 //! this module provides the [`Vbml`] struct for deserializing VBML from JSON and parsing it into a [`BoardData<ROWS, COLS>`]
 //! to be sent to a Vestaboard. it can also be used to build a [`Board<ROWS, COLS>`] directly, if desired.
 //!
-//! if ROWS and COLS are not provided, the flagship board size (6x22) will be used.
+//! if ROWS and COLS are not provided, the flagship board size (6x22) will be used. to size the board from
+//! the `style` of the VBML document instead, use [`Vbml::parse_dyn`] to get a [`DynBoardData`].
 //!
 //! # example
 //! ```
@@ -18,19 +19,32 @@
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::board::{Board, BoardData, FLAGSHIP_COLS, FLAGSHIP_ROWS};
+use crate::board::{Board, BoardData, BoardModel, DynBoardData, FLAGSHIP_COLS, FLAGSHIP_ROWS, NOTE_COLS, NOTE_ROWS};
 
+mod diagnostic;
 mod format;
+mod parser;
+pub use crate::board::{Align, CodeHandling, Justify};
+pub use diagnostic::{VbmlDiagnostic, VbmlDiagnosticKind};
 pub use format::{
-  AbsolutePosition, Align, ComponentStyle, Justify, VbmlComponent, VbmlProps, VbmlRawComponent, VbmlStyle,
-  VbmlTemplateComponent,
+  AbsolutePosition, ComponentStyle, VbmlComponent, VbmlProps, VbmlRawComponent, VbmlStyle, VbmlTemplateComponent,
 };
+pub use parser::VbmlParser;
+
+/// the largest height or width of a VBML board or component. anything larger is rejected with
+/// [`VbmlError::TooLarge`], so untrusted VBML cannot make the parser allocate unbounded memory
+pub const MAX_SIZE: usize = 256;
+
+/// a [`Vbml`] targeting the flagship Vestaboard (6x22)
+pub type FlagshipVbml = Vbml<FLAGSHIP_ROWS, FLAGSHIP_COLS>;
+/// a [`Vbml`] targeting the Vestaboard Note (3x15)
+pub type NoteVbml = Vbml<NOTE_ROWS, NOTE_COLS>;
 
 /// a Vestaboard Markup Language (VBML) object
 ///
 /// # attributes
 /// - `props`: [`VbmlProps`] - data that will be used to replace templates in the VBML components
-/// - `style`: [`VbmlStyle`] - optional style for the VBML (NOTE: DATA HERE IS CURRENTLY IGNORED)
+/// - `style`: [`VbmlStyle`] - optional size of the board, honored by [`Vbml::parse_dyn`]
 /// - `components`: a Vec of [`VbmlComponent`]s that make up the VBML
 ///
 /// # type parameters
@@ -39,12 +53,18 @@ pub use format::{
 ///
 /// # methods
 /// - [`Vbml::parse`] - parses the VBML into a [`BoardData<ROWS, COLS>`] for use in api calls
+/// - [`Vbml::parse_dyn`] - parses the VBML into a [`DynBoardData`] sized by the VBML `style`
+/// - [`Vbml::parse_model`] - parses the VBML into a [`DynBoardData`] sized for a [`BoardModel`]
+/// - [`Vbml::parse_with_diagnostics`] - parses the VBML into a [`BoardData<ROWS, COLS>`] along with a
+///   [`VbmlDiagnostic`] for everything that was cut off, covered, or replaced
+/// - [`Vbml::parser`] - creates a [`VbmlParser`] to parse the VBML with other options, such as
+///   [`CodeHandling::Strict`] or [`VbmlParser::strict`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Vbml<const ROWS: usize = FLAGSHIP_ROWS, const COLS: usize = FLAGSHIP_COLS> {
   /// data that will be used to replace templates in the VBML components
   pub props: Option<VbmlProps>,
-  /// optional style for the VBML (NOTE: DATA HERE IS CURRENTLY IGNORED)
+  /// optional size of the board, honored by [`Vbml::parse_dyn`] and ignored by [`Vbml::parse`]
   pub style: Option<VbmlStyle>,
   /// a Vec of [`VbmlComponent`]s that make up the VBML
   pub components: Vec<VbmlComponent<ROWS, COLS>>,
@@ -57,96 +77,78 @@ impl<const ROWS: usize, const COLS: usize> Vbml<ROWS, COLS> {
   /// but may still have edge cases that are not covered. if you encounter any issues,
   /// please open an issue on the repository.
   ///
+  /// the [`VbmlStyle`] of the VBML is ignored, the board is always `ROWS`x`COLS`. use
+  /// [`Vbml::parse_dyn`] to honor `style.height` and `style.width`.
+  ///
+  /// invalid character codes and characters that are not on the board are replaced with blanks, and
+  /// content that does not fit is cut off, the same as [`CodeHandling::Lossy`]. use [`Vbml::parser`] to
+  /// reject them instead.
+  ///
   /// # errors
-  /// - [`VbmlError::Regex`] if there is an error with regex replacement of template during parse
+  /// - [`VbmlError::TooLarge`] if a component is larger than [`MAX_SIZE`]
   pub fn parse(&self) -> Result<BoardData<ROWS, COLS>, VbmlError> {
-    let mut board: Board<ROWS, COLS> = BoardData::<ROWS, COLS>::default().into();
-    let mut components = self.components.to_vec();
-    components.sort_by(
-      |a, b| match (a.get_style().absolute_position, b.get_style().absolute_position) {
-        (Some(_), None) => std::cmp::Ordering::Greater,
-        (None, Some(_)) => std::cmp::Ordering::Less,
-        _ => std::cmp::Ordering::Equal,
-      },
-    );
-
-    let props = self.props.as_ref().map(|props| props.replace_template());
-
-    let mut cur_row: usize = 0;
-    let mut max_row: usize = 0;
-    let mut cur_col: usize = 0;
-
-    for component in &components {
-      let style = component.get_style();
-      let component_height = style.height.unwrap_or(ROWS as u32) as usize;
-      let component_width = style.width.unwrap_or(COLS as u32) as usize;
-      let (content_height, content_widest_width, content) = component.get_word_rows(props.as_ref());
-      tracing::trace!(
-        "component_height: {component_height}; component_width: {component_width}; content_height: {content_height}; content_widest_width: {content_widest_width};",
-      );
-      tracing::trace!("content: {:?}", content);
-
-      if cur_col + component_width > COLS {
-        cur_col = 0;
-        cur_row = max_row;
-      }
-
-      if let Some(absolute) = &style.absolute_position {
-        cur_row = absolute.y as usize;
-        cur_col = absolute.x as usize;
-      }
-
-      // indexed within the component
-      let mut starting_row = 0;
-      match &style.align {
-        Some(Align::Center) => starting_row = ((component_height - content_height) as f64 / 2.0).floor() as usize,
-        Some(Align::Bottom) => starting_row = component_height - content_height,
-        Some(Align::Justified) => starting_row = ((component_height - content_height) as f64 / 2.0).ceil() as usize,
-        _ => {}
-      }
-
-      match content {
-        Some(content_rows) => {
-          for (row_offset, content_row) in content_rows.iter().filter(|row| !row.is_empty()).enumerate() {
-            // indexed within the component
-            let mut starting_col = 0;
-
-            match &style.justify {
-              Some(Justify::Center) => starting_col = ((component_width - content_row.len()) as f64 / 2.0) as usize,
-              Some(Justify::Right) => starting_col = component_width - content_row.len(),
-              Some(Justify::Justified) => {
-                starting_col = ((component_width - content_widest_width) as f64 / 2.0) as usize
-              }
-              _ => {}
-            }
-
-            let row = cur_row + starting_row + row_offset;
-            for (col_offset, content_col) in content_row.iter().enumerate() {
-              let col = cur_col + starting_col + col_offset;
-              tracing::trace!("row: {row}; col: {col}; content_col: {content_col};",);
-
-              if row >= ROWS || col >= COLS {
-                // panic!("row or col out of bounds");
-                tracing::error!("row or col out of bounds");
-                continue;
-              }
-
-              board[row][col] = (*content_col).into();
-            }
-          }
-
-          cur_col += component_width;
-          max_row = max_row.max(cur_row + component_height);
-        }
-        None => {
-          if let VbmlComponent::Raw(raw) = component {
-            board = *raw.raw_characters
-          }
-        }
-      };
-    }
-
-    Ok(board.into())
+    self.parser().parse()
+  }
+
+  /// parses the VBML into a [`BoardData<ROWS, COLS>`] the same as [`Vbml::parse`], along with a
+  /// [`VbmlDiagnostic`] for every problem that changed how the board looks, such as text that did not fit
+  /// its component, cells outside the board, components that cover each other, props that are not defined,
+  /// and characters that were replaced
+  ///
+  /// # errors
+  /// - [`VbmlError::TooLarge`] if a component is larger than [`MAX_SIZE`]
+  pub fn parse_with_diagnostics(&self) -> Result<(BoardData<ROWS, COLS>, Vec<VbmlDiagnostic>), VbmlError> {
+    self.parser().parse_with_diagnostics()
+  }
+
+  /// parses the VBML into a [`DynBoardData`] sized by the [`VbmlStyle`] of the VBML
+  ///
+  /// `style.height` and `style.width` set the number of rows and columns of the board. if either
+  /// is not provided, `ROWS` or `COLS` is used instead. components without a height or width fill
+  /// the board in that dimension.
+  ///
+  /// # errors
+  /// - [`VbmlError::TooLarge`] if the board or a component is larger than [`MAX_SIZE`]
+  pub fn parse_dyn(&self) -> Result<DynBoardData, VbmlError> {
+    self.parser().parse_dyn()
+  }
+
+  /// parses the VBML into a [`DynBoardData`] sized for a [`BoardModel`], such as one looked up by name.
+  /// the [`VbmlStyle`] of the VBML is ignored.
+  ///
+  /// # example
+  /// ```
+  /// let model: BoardModel = "note".parse().unwrap();
+  /// let board: DynBoardData = vbml.parse_model(model).unwrap(); // a 3x15 board
+  /// ```
+  ///
+  /// # errors
+  /// - [`VbmlError::TooLarge`] if the board or a component is larger than [`MAX_SIZE`]
+  pub fn parse_model(&self, model: BoardModel) -> Result<DynBoardData, VbmlError> {
+    self.parser().parse_model(model)
+  }
+
+  /// creates a [`VbmlParser`] for the VBML, to parse it with options other than the defaults
+  ///
+  /// # example
+  /// ```
+  /// let board: BoardData = vbml.parser().codes(CodeHandling::Strict).parse()?;
+  /// ```
+  pub fn parser(&self) -> VbmlParser<'_, ROWS, COLS> {
+    VbmlParser::new(self)
+  }
+
+  /// the dimensions of the board described by the [`VbmlStyle`] of the VBML as `(rows, cols)`,
+  /// falling back to `ROWS` and `COLS`
+  pub fn dimensions(&self) -> (usize, usize) {
+    let style = self.style.as_ref();
+
+    (
+      style
+        .and_then(|style| style.height)
+        .map_or(ROWS, |height| height as usize),
+      style.and_then(|style| style.width).map_or(COLS, |width| width as usize),
+    )
   }
 }
 
@@ -204,7 +206,12 @@ impl<const ROWS: usize, const COLS: usize> TryFrom<Vbml<ROWS, COLS>> for Board<R
 /// error type for VBML
 /// - [`VbmlError::Deserialize`] if there is an error deserializing the VBML
 /// - [`VbmlError::Serialize`] if there is an error serializing the VBML
-/// - [`VbmlError::Regex`] if there is an error with regex replacement of template during parse
+/// - [`VbmlError::InvalidCode`] if a component has a character code that is not on the board, when strict
+/// - [`VbmlError::InvalidChar`] if a component has a character that is not on the board, when strict
+/// - [`VbmlError::Overflow`] if the text of a component does not fit its height and width, when strict
+/// - [`VbmlError::OutOfBounds`] if a component is not entirely on the board, when strict
+/// - [`VbmlError::MissingProp`] if a template uses a prop that is not defined, when strict
+/// - [`VbmlError::TooLarge`] if the board or a component is larger than [`MAX_SIZE`]
 #[derive(Error, Debug)]
 pub enum VbmlError {
   /// failed to deserialize into VBML
@@ -213,7 +220,32 @@ pub enum VbmlError {
   /// failed to serialize from VBML
   #[error("failed to serialize from VBML")]
   Serialize(serde_json::Error),
-  /// failed to replace VBML template
-  #[error("failed to replace VBML template")]
-  Regex(#[from] regex::Error),
+  /// a component has a character code that cannot be displayed, see `component` for the index of the
+  /// component and `code` for the code
+  #[error("component {component} has an invalid character code: {code}")]
+  InvalidCode { component: usize, code: String },
+  /// a component has a character that is not on the board and cannot be transliterated, see `component`
+  /// for the index of the component and `found` for the character
+  #[error("component {component} has a character that is not on the board: {found:?}")]
+  InvalidChar { component: usize, found: char },
+  /// the text of a component does not fit its height and width, see `component` for the index of the
+  /// component
+  #[error("component {component} has more text than fits")]
+  Overflow { component: usize },
+  /// a component is not entirely on the board, see `component` for the index of the component and `row`
+  /// and `col` for its position
+  #[error("component {component} at ({row}, {col}) is not entirely on the board")]
+  OutOfBounds { component: usize, row: usize, col: usize },
+  /// a template uses a prop that is not defined, see `component` for the index of the component and
+  /// `name` for the prop
+  #[error("component {component} uses a prop that is not defined: {name:?}")]
+  MissingProp { component: usize, name: String },
+  /// the board or a component is taller or wider than [`MAX_SIZE`], see `component` for the index of the
+  /// component or `None` for the board, and `height` and `width` for its size
+  #[error("{height}x{width} is larger than the maximum size of {MAX_SIZE}")]
+  TooLarge {
+    component: Option<usize>,
+    height: usize,
+    width: usize,
+  },
 }
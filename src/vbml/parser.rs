@@ -0,0 +1,390 @@
+use crate::board::{layout, transliterate, Board, BoardData, BoardModel, CharacterCode, CodeHandling, DynBoardData};
+
+use super::{Vbml, VbmlComponent, VbmlDiagnostic, VbmlDiagnosticKind, VbmlError, MAX_SIZE};
+
+/// parses a [`Vbml`] with options, created with [`Vbml::parser`]
+///
+/// the defaults are the same as [`Vbml::parse`]: invalid character codes and characters that are not on
+/// the board are replaced with blanks, and content that does not fit is cut off.
+#[derive(Debug, Clone)]
+pub struct VbmlParser<'a, const ROWS: usize, const COLS: usize> {
+  vbml: &'a Vbml<ROWS, COLS>,
+  codes: CodeHandling,
+  layout: CodeHandling,
+}
+
+impl<'a, const ROWS: usize, const COLS: usize> VbmlParser<'a, ROWS, COLS> {
+  pub(crate) fn new(vbml: &'a Vbml<ROWS, COLS>) -> Self {
+    VbmlParser {
+      vbml,
+      codes: CodeHandling::Lossy,
+      layout: CodeHandling::Lossy,
+    }
+  }
+
+  /// sets how character codes that cannot be displayed, such as `{43}` in a template or `43` in
+  /// `rawCharacters`, and characters that are not on the board and cannot be transliterated are handled
+  pub fn codes(mut self, handling: CodeHandling) -> Self {
+    self.codes = handling;
+    self
+  }
+
+  /// sets how components that do not fit are handled: text that does not fit the `height` and `width` of
+  /// its component, components that are not entirely on the board, and props used by a template that are
+  /// not defined. [`CodeHandling::Lossy`] cuts them off or leaves them empty, the same as the JS library
+  pub fn layout(mut self, handling: CodeHandling) -> Self {
+    self.layout = handling;
+    self
+  }
+
+  /// rejects anything that would not be shown as written, the same as both [`VbmlParser::codes`] and
+  /// [`VbmlParser::layout`] with [`CodeHandling::Strict`]
+  ///
+  /// # example
+  /// ```
+  /// let board: BoardData = vbml.parser().strict().parse()?;
+  /// ```
+  pub fn strict(self) -> Self {
+    self.codes(CodeHandling::Strict).layout(CodeHandling::Strict)
+  }
+
+  /// parses the VBML into a [`BoardData<ROWS, COLS>`]. see [`Vbml::parse`]
+  ///
+  /// # errors
+  /// - [`VbmlError::TooLarge`] if a component is larger than [`MAX_SIZE`]
+  /// - [`VbmlError::InvalidCode`] or [`VbmlError::InvalidChar`] with [`CodeHandling::Strict`] codes
+  /// - [`VbmlError::Overflow`], [`VbmlError::OutOfBounds`], or [`VbmlError::MissingProp`] with
+  ///   [`CodeHandling::Strict`] layout
+  pub fn parse(&self) -> Result<BoardData<ROWS, COLS>, VbmlError> {
+    self.parse_with_diagnostics().map(|(board, _)| board)
+  }
+
+  /// parses the VBML into a [`BoardData<ROWS, COLS>`] along with every [`VbmlDiagnostic`] found while
+  /// laying it out, such as text that was cut off or components that cover each other
+  ///
+  /// # example
+  /// ```
+  /// let (board, diagnostics) = vbml.parser().parse_with_diagnostics()?;
+  /// for diagnostic in &diagnostics {
+  ///   tracing::warn!("{diagnostic}");
+  /// }
+  /// ```
+  ///
+  /// # errors
+  /// - [`VbmlError::TooLarge`] if a component is larger than [`MAX_SIZE`]
+  /// - [`VbmlError::InvalidCode`] or [`VbmlError::InvalidChar`] with [`CodeHandling::Strict`] codes
+  /// - [`VbmlError::Overflow`], [`VbmlError::OutOfBounds`], or [`VbmlError::MissingProp`] with
+  ///   [`CodeHandling::Strict`] layout
+  pub fn parse_with_diagnostics(&self) -> Result<(BoardData<ROWS, COLS>, Vec<VbmlDiagnostic>), VbmlError> {
+    let mut board: Board<ROWS, COLS> = BoardData::<ROWS, COLS>::default().into();
+    let diagnostics = self.parse_into(ROWS, COLS, |row, col, code| board[row][col] = code)?;
+
+    Ok((board.into(), diagnostics))
+  }
+
+  /// parses the VBML into a [`DynBoardData`] sized by the [`super::VbmlStyle`] of the VBML.
+  /// see [`Vbml::parse_dyn`]
+  ///
+  /// # errors
+  /// - [`VbmlError::TooLarge`] if the board or a component is larger than [`MAX_SIZE`]
+  /// - [`VbmlError::InvalidCode`] or [`VbmlError::InvalidChar`] with [`CodeHandling::Strict`] codes
+  /// - [`VbmlError::Overflow`], [`VbmlError::OutOfBounds`], or [`VbmlError::MissingProp`] with
+  ///   [`CodeHandling::Strict`] layout
+  pub fn parse_dyn(&self) -> Result<DynBoardData, VbmlError> {
+    self.parse_dyn_with_diagnostics().map(|(board, _)| board)
+  }
+
+  /// parses the VBML into a [`DynBoardData`] sized by the [`super::VbmlStyle`] of the VBML, along with
+  /// every [`VbmlDiagnostic`]. see [`VbmlParser::parse_with_diagnostics`]
+  ///
+  /// # errors
+  /// - [`VbmlError::TooLarge`] if the board or a component is larger than [`MAX_SIZE`]
+  /// - [`VbmlError::InvalidCode`] or [`VbmlError::InvalidChar`] with [`CodeHandling::Strict`] codes
+  /// - [`VbmlError::Overflow`], [`VbmlError::OutOfBounds`], or [`VbmlError::MissingProp`] with
+  ///   [`CodeHandling::Strict`] layout
+  pub fn parse_dyn_with_diagnostics(&self) -> Result<(DynBoardData, Vec<VbmlDiagnostic>), VbmlError> {
+    let (rows, cols) = self.vbml.dimensions();
+    self.parse_sized(rows, cols)
+  }
+
+  /// parses the VBML into a [`DynBoardData`] sized for a [`BoardModel`]. see [`Vbml::parse_model`]
+  ///
+  /// # errors
+  /// - [`VbmlError::TooLarge`] if the board or a component is larger than [`MAX_SIZE`]
+  /// - [`VbmlError::InvalidCode`] or [`VbmlError::InvalidChar`] with [`CodeHandling::Strict`] codes
+  /// - [`VbmlError::Overflow`], [`VbmlError::OutOfBounds`], or [`VbmlError::MissingProp`] with
+  ///   [`CodeHandling::Strict`] layout
+  pub fn parse_model(&self, model: BoardModel) -> Result<DynBoardData, VbmlError> {
+    self.parse_sized(model.rows(), model.cols()).map(|(board, _)| board)
+  }
+
+  /// parses the VBML into a [`DynBoardData`] of `rows`x`cols`
+  ///
+  /// # errors
+  /// - [`VbmlError::TooLarge`] if `rows` or `cols` is larger than [`MAX_SIZE`]
+  fn parse_sized(&self, rows: usize, cols: usize) -> Result<(DynBoardData, Vec<VbmlDiagnostic>), VbmlError> {
+    if rows > MAX_SIZE || cols > MAX_SIZE {
+      return Err(VbmlError::TooLarge {
+        component: None,
+        height: rows,
+        width: cols,
+      });
+    }
+    let mut board = DynBoardData::try_new(rows, cols).map_err(|_| VbmlError::TooLarge {
+      component: None,
+      height: rows,
+      width: cols,
+    })?;
+
+    let cells = board.cells_mut();
+    let diagnostics = self.parse_into(rows, cols, |row, col, code| cells[row * cols + col] = code)?;
+
+    Ok((board, diagnostics))
+  }
+
+  /// checks that a component only has codes and characters that are on the board
+  ///
+  /// # errors
+  /// - [`VbmlError::InvalidCode`] if the component has a code that cannot be displayed
+  /// - [`VbmlError::InvalidChar`] if the component has a character that would be dropped
+  fn check_codes(&self, index: usize, component: &VbmlComponent<ROWS, COLS>) -> Result<(), VbmlError> {
+    match component {
+      VbmlComponent::Raw(raw) => match raw.raw_characters.invalid_cells().first() {
+        Some(cell) => Err(VbmlError::InvalidCode {
+          component: index,
+          code: cell.code.to_string(),
+        }),
+        None => Ok(()),
+      },
+      VbmlComponent::Template(template) => {
+        if let Some(code) = template.invalid_codes(self.vbml.props.as_ref()).into_iter().next() {
+          return Err(VbmlError::InvalidCode { component: index, code });
+        }
+
+        let props = self.vbml.props.as_ref().map(|props| props.replace_template());
+        let text = template.render(props.as_ref());
+        match transliterate(&text).dropped().next() {
+          Some(dropped) => Err(VbmlError::InvalidChar {
+            component: index,
+            found: dropped.original(),
+          }),
+          None => Ok(()),
+        }
+      }
+    }
+  }
+
+  /// checks the diagnostics of a component with [`CodeHandling::Strict`] layout
+  ///
+  /// # errors
+  /// - [`VbmlError::Overflow`] if text was cut off
+  /// - [`VbmlError::OutOfBounds`] if cells were outside the board
+  /// - [`VbmlError::MissingProp`] if a prop is not defined
+  fn check_layout(&self, index: usize, row: usize, col: usize, kinds: &[VbmlDiagnosticKind]) -> Result<(), VbmlError> {
+    if self.layout != CodeHandling::Strict {
+      return Ok(());
+    }
+
+    match kinds.iter().find_map(|kind| match kind {
+      VbmlDiagnosticKind::TruncatedText => Some(VbmlError::Overflow { component: index }),
+      VbmlDiagnosticKind::ClippedCells { .. } => Some(VbmlError::OutOfBounds {
+        component: index,
+        row,
+        col,
+      }),
+      VbmlDiagnosticKind::UnknownProp { name } => Some(VbmlError::MissingProp {
+        component: index,
+        name: name.clone(),
+      }),
+      _ => None,
+    }) {
+      Some(error) => Err(error),
+      None => Ok(()),
+    }
+  }
+
+  /// lays out the components on a `rows`x`cols` board, calling `put` with the row, column, and
+  /// character code of every cell that is written. `put` is only called with positions inside the board.
+  ///
+  /// returns the diagnostics of every component, in the order the components are drawn
+  fn parse_into(
+    &self,
+    rows: usize,
+    cols: usize,
+    mut put: impl FnMut(usize, usize, u8),
+  ) -> Result<Vec<VbmlDiagnostic>, VbmlError> {
+    if self.codes == CodeHandling::Strict {
+      for (index, component) in self.vbml.components.iter().enumerate() {
+        self.check_codes(index, component)?;
+      }
+    }
+
+    let mut components: Vec<_> = self.vbml.components.iter().enumerate().collect();
+    components.sort_by(
+      |(_, a), (_, b)| match (a.get_style().absolute_position, b.get_style().absolute_position) {
+        (Some(_), None) => std::cmp::Ordering::Greater,
+        (None, Some(_)) => std::cmp::Ordering::Less,
+        _ => std::cmp::Ordering::Equal,
+      },
+    );
+
+    let props = self.vbml.props.as_ref().map(|props| props.replace_template());
+
+    let mut cur_row: usize = 0;
+    let mut max_row: usize = 0;
+    let mut cur_col: usize = 0;
+
+    let mut diagnostics = Vec::new();
+    // the template component that last wrote each cell, to find components that cover each other
+    let mut owners: Vec<Option<usize>> = vec![None; rows * cols];
+    let mut cover = |overlaps: &mut Vec<(usize, usize)>, index: usize, cell: usize, owner: Option<usize>| {
+      if let Some(other) = owners[cell].filter(|other| *other != index) {
+        match overlaps.iter_mut().find(|(earlier, _)| *earlier == other) {
+          Some((_, count)) => *count += 1,
+          None => overlaps.push((other, 1)),
+        }
+      }
+      owners[cell] = owner;
+    };
+
+    for (index, component) in components {
+      let style = component.get_style();
+      let component_height = style.height.map_or(rows, |height| height as usize);
+      let component_width = style.width.map_or(cols, |width| width as usize);
+      tracing::trace!("component_height: {component_height}; component_width: {component_width};");
+      if component_height > MAX_SIZE || component_width > MAX_SIZE {
+        return Err(VbmlError::TooLarge {
+          component: Some(index),
+          height: component_height,
+          width: component_width,
+        });
+      }
+
+      // absolute positions can be anywhere up to u32::MAX, so every addition to them saturates
+      if cur_col.saturating_add(component_width) > cols {
+        cur_col = 0;
+        cur_row = max_row;
+      }
+
+      if let Some(absolute) = &style.absolute_position {
+        cur_row = absolute.y as usize;
+        cur_col = absolute.x as usize;
+      }
+      let (row, col) = (cur_row, cur_col);
+
+      let mut kinds = Vec::new();
+      let mut overlaps = Vec::new();
+
+      match component {
+        VbmlComponent::Template(template) => {
+          // a component can be partly outside the board without any of its content being clipped
+          let outside = row.saturating_add(component_height) > rows || col.saturating_add(component_width) > cols;
+          if self.layout == CodeHandling::Strict && outside {
+            return Err(VbmlError::OutOfBounds {
+              component: index,
+              row,
+              col,
+            });
+          }
+
+          let used_props = self.vbml.props.as_ref();
+          kinds.extend(
+            template
+              .unknown_props(used_props)
+              .into_iter()
+              .map(|name| VbmlDiagnosticKind::UnknownProp { name }),
+          );
+          kinds.extend(
+            template
+              .invalid_codes(used_props)
+              .into_iter()
+              .map(|code| VbmlDiagnosticKind::InvalidCode { code }),
+          );
+
+          let text = transliterate(&template.render(props.as_ref()));
+          kinds.extend(text.replacements.into_iter().map(VbmlDiagnosticKind::Replaced));
+
+          let (content_height, content_widest_width, content, truncated) =
+            layout::wrap_words_truncated(&text.text, component_height, component_width, rows);
+          tracing::trace!("content_height: {content_height}; content_widest_width: {content_widest_width};");
+          tracing::trace!("content: {:?}", content);
+          if truncated {
+            kinds.push(VbmlDiagnosticKind::TruncatedText);
+          }
+          self.check_layout(index, row, col, &kinds)?;
+
+          let mut clipped = 0;
+          layout::place_rows(
+            &content,
+            false,
+            content_height,
+            content_widest_width,
+            component_height,
+            component_width,
+            style.justify.as_ref(),
+            style.align.as_ref(),
+            |row_offset, col_offset, content_col| {
+              let row = cur_row.saturating_add(row_offset);
+              let col = cur_col.saturating_add(col_offset);
+              tracing::trace!("row: {row}; col: {col}; content_col: {content_col};",);
+
+              if row >= rows || col >= cols {
+                clipped += 1;
+                return;
+              }
+
+              cover(&mut overlaps, index, row * cols + col, Some(index));
+              put(row, col, content_col.into());
+            },
+          );
+
+          if clipped > 0 {
+            kinds.push(VbmlDiagnosticKind::ClippedCells { count: clipped });
+          }
+
+          cur_col = cur_col.saturating_add(component_width);
+          max_row = max_row.max(cur_row.saturating_add(component_height));
+        }
+        VbmlComponent::Raw(raw) => {
+          let (raw_rows, raw_cols) = (raw.raw_characters.rows(), raw.raw_characters.cols());
+          let clipped = raw_rows * raw_cols - raw_rows.min(rows) * raw_cols.min(cols);
+          if clipped > 0 {
+            kinds.push(VbmlDiagnosticKind::ClippedCells { count: clipped });
+          }
+          kinds.extend(
+            raw
+              .raw_characters
+              .invalid_cells()
+              .into_iter()
+              .map(|cell| VbmlDiagnosticKind::InvalidCode {
+                code: cell.code.to_string(),
+              }),
+          );
+          self.check_layout(index, row, col, &kinds)?;
+
+          for row in 0..rows {
+            for col in 0..cols {
+              let code = raw.raw_characters.get(row, col).unwrap_or(0);
+              cover(&mut overlaps, index, row * cols + col, None);
+              put(row, col, if CharacterCode::is_cell_code(code) { code } else { 0 });
+            }
+          }
+        }
+      };
+
+      kinds.extend(
+        overlaps
+          .into_iter()
+          .map(|(other, count)| VbmlDiagnosticKind::Overlap { other, count }),
+      );
+      diagnostics.extend(kinds.into_iter().map(|kind| VbmlDiagnostic {
+        component: index,
+        row,
+        col,
+        kind,
+      }));
+    }
+
+    Ok(diagnostics)
+  }
+}
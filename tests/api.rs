@@ -34,6 +34,71 @@ fn it_creates_local_api() {
   let _api: Vestaboard<LocalConfig> = Vestaboard::new_local_api(config);
 }
 
+#[cfg(any(feature = "rw", feature = "subscription", feature = "local"))]
+fn assert_board_client<C: BoardClient + Send + Sync>(_client: &C) {}
+
+#[test]
+#[cfg(feature = "rw")]
+fn it_implements_board_client_for_rw() {
+  let api: Vestaboard<RWConfig> = Vestaboard::new_rw_api(RWConfig {
+    read_write_key: "test".to_string(),
+  });
+
+  assert_board_client(&api);
+}
+
+#[test]
+#[cfg(feature = "subscription")]
+fn it_implements_board_client_for_subscription() {
+  let api: Vestaboard<SubscriptionConfig> = Vestaboard::new_subscription_api(SubscriptionConfig {
+    api_key: "test".to_string(),
+    api_secret: "test".to_string(),
+  });
+  let board = api.subscription("subscription-id");
+
+  assert_eq!(board.subscription_id(), "subscription-id");
+  assert_board_client(&board);
+}
+
+#[test]
+#[cfg(feature = "local")]
+fn it_implements_board_client_for_local() {
+  let api: Vestaboard<LocalConfig> = Vestaboard::new_local_api(LocalConfig {
+    api_key: "test".to_string(),
+    ip_address: "127.0.0.1".parse().unwrap(),
+  });
+
+  assert_board_client(&api);
+}
+
+#[test]
+#[cfg(feature = "rw")]
+fn it_creates_clients_for_runtime_models() {
+  let config = RWConfig {
+    read_write_key: "test".to_string(),
+  };
+
+  for model in BoardModel::ALL {
+    let api = ModelVestaboard::new_rw_api(model, config.clone());
+    assert_eq!(api.model(), model);
+  }
+}
+
+#[test]
+#[cfg(feature = "local")]
+fn it_creates_local_clients_for_runtime_models() {
+  let model: BoardModel = "note-white".parse().unwrap();
+  let api = ModelVestaboard::new_local_api(
+    model,
+    LocalConfig {
+      api_key: "test".to_string(),
+      ip_address: "127.0.0.1".parse().unwrap(),
+    },
+  );
+
+  assert_eq!(api.model(), BoardModel::NoteWhite);
+}
+
 #[cfg(any(feature = "rw", feature = "subscription", feature = "local"))]
 struct TestConfig {
   #[cfg(feature = "rw")]
@@ -1,4 +1,7 @@
-use vestaboard::board::{Board, BoardData, BoardError, FLAGSHIP_COLS, FLAGSHIP_ROWS};
+use vestaboard::board::{
+  Align, Board, BoardData, BoardEdition, BoardError, BoardModel, CharacterCode, DynBoardData, Justify, NoteBoard,
+  TextLayout, FLAGSHIP_COLS, FLAGSHIP_ROWS, NOTE_COLS, NOTE_ROWS,
+};
 
 #[test]
 fn it_makes_board() {
@@ -79,3 +82,245 @@ fn it_fails_too_many_cols() {
   let board: Result<BoardData<FLAGSHIP_ROWS, FLAGSHIP_COLS>, BoardError> = data.parse();
   assert!(board.is_err());
 }
+
+#[test]
+fn it_converts_dyn_board() {
+  let board = BoardData([[1, 2, 3], [4, 5, 6]]);
+
+  let dyn_board = DynBoardData::from(board.clone());
+  assert_eq!(dyn_board.rows(), 2);
+  assert_eq!(dyn_board.cols(), 3);
+  assert_eq!(dyn_board.get(1, 2), Some(6));
+  assert_eq!(dyn_board.get(2, 0), None);
+
+  let round_trip: BoardData<2, 3> = dyn_board.clone().try_into().expect("failed to convert board");
+  assert_eq!(round_trip, board);
+
+  let mismatch: Result<BoardData<3, 2>, BoardError> = dyn_board.try_into();
+  assert!(matches!(
+    mismatch,
+    Err(BoardError::DimensionMismatch {
+      expected: (3, 2),
+      found: (2, 3)
+    })
+  ));
+}
+
+#[test]
+fn it_deserializes_dyn_board() {
+  let board: DynBoardData = serde_json::from_str("[[8,9],[0,0]]").expect("failed to deserialize board");
+  assert_eq!(board.row(0), Some([8, 9].as_slice()));
+  assert_eq!(serde_json::to_string(&board).unwrap(), "[[8,9],[0,0]]");
+
+  let ragged: Result<DynBoardData, _> = serde_json::from_str("[[8,9],[0]]");
+  assert!(ragged.is_err());
+}
+
+#[test]
+fn it_keeps_the_rows_of_zero_width_dyn_boards() {
+  let board = DynBoardData::new(3, 0);
+  assert_eq!(board.iter_rows().count(), 3);
+  assert_eq!(board.row(2), Some([].as_slice()));
+
+  let rows: Vec<Vec<u8>> = board.clone().into();
+  assert_eq!(rows, vec![Vec::<u8>::new(); 3]);
+  assert_eq!(DynBoardData::try_from(rows).unwrap(), board);
+  assert_eq!(serde_json::to_string(&board).unwrap(), "[[],[],[]]");
+}
+
+#[test]
+#[should_panic(expected = "board dimensions overflow")]
+fn it_rejects_dyn_boards_that_overflow() {
+  DynBoardData::new(usize::MAX, 2);
+}
+
+#[test]
+fn it_reports_dyn_boards_that_overflow() {
+  assert!(matches!(
+    DynBoardData::try_new(usize::MAX, 2),
+    Err(BoardError::TooLarge {
+      rows: usize::MAX,
+      cols: 2
+    })
+  ));
+  assert_eq!(DynBoardData::try_new(2, 3).unwrap(), DynBoardData::new(2, 3));
+  assert!(matches!(
+    DynBoardData::from_grid("A", usize::MAX, 2),
+    Err(BoardError::TooLarge { .. })
+  ));
+}
+
+#[test]
+fn it_sets_dyn_board_cells() {
+  let mut board = DynBoardData::new(1, 4);
+
+  board.set(0, 3, 1).expect("failed to set cell");
+  assert_eq!(board.row(0), Some([0, 0, 0, 1].as_slice()));
+  assert!(matches!(
+    board.set(0, 4, 1),
+    Err(BoardError::OutOfBounds { row: 0, col: 4 })
+  ));
+}
+
+#[test]
+fn it_looks_up_models_by_name() {
+  let note: BoardModel = "note".parse().expect("failed to parse model");
+  assert_eq!(note, BoardModel::Note);
+  assert_eq!(note.dimensions(), (NOTE_ROWS, NOTE_COLS));
+  assert_eq!(note.edition(), BoardEdition::Black);
+
+  let white: BoardModel = "Flagship-White".parse().expect("failed to parse model");
+  assert_eq!(white, BoardModel::FlagshipWhite);
+  assert_eq!(white.dimensions(), (FLAGSHIP_ROWS, FLAGSHIP_COLS));
+  assert_eq!(white.edition(), BoardEdition::White);
+
+  assert_eq!("note-black".parse::<BoardModel>().ok(), Some(BoardModel::Note));
+  assert!(matches!("tiny".parse::<BoardModel>(), Err(BoardError::UnknownModel(_))));
+  for name in ["flagship-white-black", "note-white-black", "note-black-black"] {
+    assert!(
+      matches!(name.parse::<BoardModel>(), Err(BoardError::UnknownModel(_))),
+      "{name}"
+    );
+  }
+
+  for model in BoardModel::ALL {
+    assert_eq!(model.to_string().parse::<BoardModel>().ok(), Some(model));
+  }
+}
+
+#[test]
+fn it_describes_model_capabilities() {
+  let black = BoardModel::Note.capabilities();
+  assert_eq!(black.edition, BoardEdition::Black);
+  assert_eq!(black.blank, CharacterCode::Black);
+  assert!(black.shows_color(CharacterCode::White));
+  assert!(!black.shows_color(CharacterCode::Black));
+
+  let white = BoardModel::FlagshipWhite.capabilities();
+  assert_eq!(white.blank, CharacterCode::White);
+  assert!(white.shows_color(CharacterCode::Black));
+  assert!(!white.shows_color(CharacterCode::White));
+
+  for model in BoardModel::ALL {
+    let capabilities = model.capabilities();
+    assert_eq!(capabilities.edition, model.edition());
+    assert!(capabilities.shows_color(CharacterCode::Filled));
+    assert!(!capabilities.shows_color(CharacterCode::A));
+  }
+}
+
+#[test]
+fn it_finds_models_by_dimensions() {
+  let models = BoardModel::with_dimensions(NOTE_ROWS, NOTE_COLS).collect::<Vec<_>>();
+  assert_eq!(models, vec![BoardModel::Note, BoardModel::NoteWhite]);
+
+  let board: NoteBoard = BoardModel::Note
+    .blank_board()
+    .try_into()
+    .expect("failed to convert board");
+  assert_eq!(board, NoteBoard::default());
+}
+
+#[test]
+fn it_parses_board_with_const_dimensions() {
+  let data = "[[1,2,3],[4,5,6]]";
+
+  let board: BoardData<2, 3> = data.parse().expect("failed to parse board");
+  assert_eq!(board, [[1, 2, 3], [4, 5, 6]]);
+
+  let board: DynBoardData = data.parse().expect("failed to parse board");
+  assert_eq!((board.rows(), board.cols()), (2, 3));
+}
+
+#[test]
+fn it_rejects_uneven_rows() {
+  let row = |cols: usize| format!("[{}]", vec!["0"; cols].join(","));
+  let rows = [row(21), row(23), row(22), row(22), row(22), row(22)];
+  let data = format!("[{}]", rows.join(","));
+
+  let board: Result<BoardData, BoardError> = data.parse();
+  assert!(matches!(
+    board,
+    Err(BoardError::TooFewCols {
+      row: 0,
+      found: 21,
+      expected: 22
+    })
+  ));
+
+  let board: Result<DynBoardData, BoardError> = format!("[{},{}]", row(2), row(3)).parse();
+  assert!(matches!(
+    board,
+    Err(BoardError::TooManyCols {
+      row: 1,
+      col: 2,
+      expected: 2
+    })
+  ));
+}
+
+#[test]
+fn it_reports_row_count_errors() {
+  let board: Result<BoardData<2, 1>, BoardError> = "[[0],[0],[0]]".parse();
+  assert!(matches!(board, Err(BoardError::TooManyRows { row: 2, expected: 2 })));
+
+  let board: Result<BoardData<2, 1>, BoardError> = "[[0]]".parse();
+  assert!(matches!(board, Err(BoardError::TooFewRows { found: 1, expected: 2 })));
+}
+
+#[test]
+fn it_reports_invalid_cells() {
+  let board: Result<BoardData<2, 2>, BoardError> = "[[0,0],[0,72]]".parse();
+  assert!(matches!(board, Err(BoardError::InvalidCode { row: 1, col: 1, value }) if value == "72"));
+
+  let board: Result<BoardData<2, 2>, BoardError> = "[[0,a],[0,0]]".parse();
+  assert!(matches!(board, Err(BoardError::InvalidChar { row: 0, col: 1, value }) if value == "a"));
+
+  let board: Result<BoardData<2, 2>, BoardError> = "[[0,0] [0,0]]".parse();
+  assert!(matches!(
+    board,
+    Err(BoardError::UnexpectedChar {
+      index: 7,
+      found: '[',
+      ..
+    })
+  ));
+
+  let board: Result<BoardData<2, 2>, BoardError> = "[[0,,0],[0,0]]".parse();
+  assert!(matches!(
+    board,
+    Err(BoardError::UnexpectedChar {
+      index: 4,
+      found: ',',
+      ..
+    })
+  ));
+}
+
+#[test]
+fn it_lays_out_text() {
+  let board: BoardData<3, 6> = BoardData::centered("hi there");
+  assert_eq!(board, [[0, 0, 8, 9, 0, 0], [20, 8, 5, 18, 5, 0], [0; 6]]);
+
+  let layout = TextLayout::default().justify(Justify::Right).align(Align::Bottom);
+  let board: BoardData<2, 4> = BoardData::from_text("ab", layout);
+  assert_eq!(board, [[0; 4], [0, 0, 1, 2]]);
+}
+
+#[test]
+fn it_collapses_blank_lines_like_vbml() {
+  let board: BoardData<4, 6> = BoardData::from_text("ab\n\ncd", TextLayout::default());
+  assert_eq!(board, [[1, 2, 0, 0, 0, 0], [3, 4, 0, 0, 0, 0], [0; 6], [0; 6]]);
+}
+
+#[test]
+fn it_cuts_off_text_without_wrapping() {
+  let board: BoardData<2, 3> = BoardData::from_text("abcdef\ngh\nij", TextLayout::default().wrap(false));
+  assert_eq!(board, [[1, 2, 3], [7, 8, 0]]);
+}
+
+#[test]
+fn it_drops_overflowing_text() {
+  let board: BoardData<2, 3> = BoardData::from_text("one two three four five", TextLayout::centered());
+  assert_eq!(board, [[15, 14, 5], [20, 23, 15]]);
+}
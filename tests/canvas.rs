@@ -0,0 +1,89 @@
+use vestaboard::board::{BoardData, BoardError, CharacterCode, Rect};
+
+#[test]
+fn it_gets_and_sets_cells() {
+  let mut board = BoardData::<2, 3>::default();
+
+  board.set_cell(1, 2, CharacterCode::Red).expect("failed to set cell");
+  assert_eq!(board.cell(1, 2), Some(CharacterCode::Red));
+  assert_eq!(board.cell(2, 0), None);
+  assert!(matches!(
+    board.set_cell(0, 3, CharacterCode::A),
+    Err(BoardError::OutOfBounds { row: 0, col: 3 })
+  ));
+}
+
+#[test]
+fn it_writes_text() {
+  let mut board = BoardData::<2, 5>::default();
+
+  board.write_text(1, 1, "hey").expect("failed to write text");
+  assert_eq!(board, [[0; 5], [0, 8, 5, 25, 0]]);
+
+  assert!(matches!(
+    board.write_text(0, 3, "hey"),
+    Err(BoardError::OutOfBounds { row: 0, col: 5 })
+  ));
+  assert_eq!(board.0[0], [0; 5], "failed writes must leave the board untouched");
+}
+
+#[test]
+fn it_fills_and_draws_lines() {
+  let mut board = BoardData::<3, 4>::default();
+
+  board.fill_rect(Rect::new(1, 1, 2, 2), CharacterCode::Blue).unwrap();
+  board.draw_hline(0, 0, 4, CharacterCode::Red).unwrap();
+  board.draw_vline(0, 3, 3, CharacterCode::Green).unwrap();
+  assert_eq!(board, [[63, 63, 63, 66], [0, 67, 67, 66], [0, 67, 67, 66]]);
+
+  assert!(board.fill_rect(Rect::new(2, 2, 2, 1), CharacterCode::Red).is_err());
+  assert!(board.draw_vline(0, 0, 0, CharacterCode::Red).is_ok());
+}
+
+#[test]
+fn it_writes_newlines_as_blanks() {
+  let mut board = BoardData::<2, 3>::default();
+
+  board.fill(CharacterCode::Red);
+  board.set_cell(0, 0, CharacterCode::Newline).unwrap();
+  board.fill_rect(Rect::new(1, 1, 1, 2), CharacterCode::Newline).unwrap();
+  assert_eq!(board, [[0, 63, 63], [63, 0, 0]]);
+
+  board.fill(CharacterCode::Newline);
+  assert_eq!(board, [[0; 3]; 2]);
+  assert!(board.invalid_cells().is_empty());
+}
+
+#[test]
+fn it_draws_borders() {
+  let mut board = BoardData::<3, 4>::default();
+
+  board.draw_border(board.bounds(), CharacterCode::Orange).unwrap();
+  assert_eq!(board, [[64, 64, 64, 64], [64, 0, 0, 64], [64, 64, 64, 64]]);
+}
+
+#[test]
+fn it_blits_with_transparency() {
+  let mut board = BoardData::<2, 4>::default();
+  board.fill(CharacterCode::Yellow);
+
+  let sprite = BoardData([[1, 0], [0, 2]]);
+  board.blit(&sprite, 0, 1, Some(CharacterCode::Blank)).unwrap();
+  assert_eq!(board, [[65, 1, 65, 65], [65, 65, 2, 65]]);
+
+  board.blit(&sprite, 0, 2, None).unwrap();
+  assert_eq!(board, [[65, 1, 1, 0], [65, 65, 0, 2]]);
+
+  assert!(board.blit(&sprite, 1, 3, None).is_err());
+}
+
+#[test]
+fn it_blits_regions() {
+  let mut board = BoardData::<1, 3>::default();
+  let source = BoardData([[1, 2, 3], [4, 5, 6]]);
+
+  board.blit_region(&source, Rect::new(1, 1, 1, 2), 0, 0, None).unwrap();
+  assert_eq!(board, [[5, 6, 0]]);
+
+  assert!(board.blit_region(&source, Rect::new(1, 2, 1, 2), 0, 0, None).is_err());
+}
@@ -0,0 +1,130 @@
+use vestaboard::board::{BoardData, BoardError, BoardPatch, CellChange, CharacterCode, PatchRun};
+
+#[test]
+fn it_diffs_identical_boards() {
+  let board = BoardData([[1, 2], [3, 4]]);
+
+  let diff = board.diff(&board.clone());
+  assert!(diff.is_empty());
+  assert_eq!(diff.changed_cells(), 0);
+  assert!(diff.to_patch().is_empty());
+}
+
+#[test]
+fn it_diffs_changed_cells() {
+  let old = BoardData([[1, 2, 3], [0, 0, 0]]);
+  let new = BoardData([[1, 8, 9], [0, 0, 63]]);
+
+  let diff = old.diff(&new);
+  assert_eq!(
+    diff.changes(),
+    [
+      CellChange {
+        row: 0,
+        col: 1,
+        old: CharacterCode::B,
+        new: CharacterCode::H
+      },
+      CellChange {
+        row: 0,
+        col: 2,
+        old: CharacterCode::C,
+        new: CharacterCode::I
+      },
+      CellChange {
+        row: 1,
+        col: 2,
+        old: CharacterCode::Blank,
+        new: CharacterCode::Red
+      },
+    ]
+  );
+  assert_eq!(diff.changed_cells(), 3);
+  assert_eq!(diff.changed_rows(), 2);
+  assert_eq!(diff.changed_cols(), 2);
+  assert_eq!(
+    diff.to_string(),
+    "(0, 1): 'B' -> 'H'\n(0, 2): 'C' -> 'I'\n(1, 2): ' ' -> '🟥'\n"
+  );
+}
+
+#[test]
+fn it_round_trips_patches() {
+  let mut old = BoardData([[1, 2, 3, 4], [0, 0, 0, 0]]);
+  let new = BoardData([[1, 8, 9, 4], [5, 0, 0, 6]]);
+
+  let patch = old.diff(&new).to_patch();
+  assert_eq!(
+    patch.runs,
+    vec![
+      PatchRun {
+        row: 0,
+        col: 1,
+        codes: vec![8, 9]
+      },
+      PatchRun {
+        row: 1,
+        col: 0,
+        codes: vec![5]
+      },
+      PatchRun {
+        row: 1,
+        col: 3,
+        codes: vec![6]
+      },
+    ]
+  );
+
+  let json = serde_json::to_string(&patch).expect("failed to serialize patch");
+  assert_eq!(json, "[[0,1,[8,9]],[1,0,[5]],[1,3,[6]]]");
+
+  let patch: BoardPatch = serde_json::from_str(&json).expect("failed to deserialize patch");
+  old.apply_patch(&patch).expect("failed to apply patch");
+  assert_eq!(old, new);
+}
+
+#[test]
+fn it_round_trips_patches_with_invalid_codes() {
+  let mut old = BoardData([[0, 43, 1]]);
+  let new = BoardData([[99, 0, 1]]);
+
+  let diff = old.diff(&new);
+  assert_eq!(diff.changed_cells(), 2);
+  assert_eq!(diff.changes()[0].new, CharacterCode::Blank);
+  assert_eq!(diff.changes()[1].old, CharacterCode::Blank);
+
+  let patch = diff.to_patch();
+  assert_eq!(
+    patch.runs,
+    vec![PatchRun {
+      row: 0,
+      col: 0,
+      codes: vec![99, 0]
+    }]
+  );
+  old.apply_patch(&patch).expect("failed to apply patch");
+  assert_eq!(old, new);
+}
+
+#[test]
+fn it_rejects_out_of_bounds_patches() {
+  let mut board = BoardData([[0, 0], [0, 0]]);
+  let patch: BoardPatch = serde_json::from_str("[[0,0,[1]],[1,1,[2,3]]]").unwrap();
+
+  assert!(matches!(
+    board.apply_patch(&patch),
+    Err(BoardError::OutOfBounds { row: 1, col: 2 })
+  ));
+  assert_eq!(board, [[0, 0], [0, 0]]);
+}
+
+#[test]
+fn it_skips_empty_runs() {
+  let mut board = BoardData::<6, 22>::default();
+  let patch: BoardPatch = serde_json::from_str("[[99,0,[]],[0,99,[]],[1,1,[8]]]").unwrap();
+  assert!(!patch.is_empty());
+
+  board.apply_patch(&patch).expect("failed to apply patch");
+  assert_eq!(board.0[1][1], 8);
+  assert_eq!(board.iter().flatten().filter(|code| **code != 0).count(), 1);
+}
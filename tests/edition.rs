@@ -0,0 +1,77 @@
+use vestaboard::board::{tile_color, BoardData, BoardEdition, CharacterCode, DynBoardData, Rgb, TerminalOptions};
+
+#[test]
+fn it_shows_filled_as_the_opposite_of_blank() {
+  assert_eq!(BoardEdition::Black.filled(), CharacterCode::White);
+  assert_eq!(BoardEdition::White.filled(), CharacterCode::Black);
+
+  assert_eq!(BoardEdition::Black.to_char(CharacterCode::Filled), '⬜');
+  assert_eq!(BoardEdition::White.to_char(CharacterCode::Filled), '⬛');
+  assert_eq!(BoardEdition::White.to_char(CharacterCode::White), '⬜');
+
+  // without an edition, filled has its own char so it converts back to filled
+  let filled: char = CharacterCode::Filled.into();
+  assert_eq!(CharacterCode::try_from(filled), Ok(CharacterCode::Filled));
+
+  assert_eq!(
+    BoardEdition::White.tile_color(CharacterCode::Filled),
+    Some(Rgb(0, 0, 0))
+  );
+  assert_eq!(
+    tile_color(CharacterCode::Filled),
+    BoardEdition::Black.tile_color(CharacterCode::Filled)
+  );
+  assert_eq!(
+    BoardEdition::White.tile_color(CharacterCode::Red),
+    tile_color(CharacterCode::Red)
+  );
+}
+
+#[test]
+fn it_converts_between_editions() {
+  let original = BoardData([[0, 71, 69, 70, 1, 63]]);
+
+  let mut board = original.clone();
+  board.convert_edition(BoardEdition::Black, BoardEdition::White);
+  assert_eq!(board.0, [[70, 0, 0, 70, 1, 63]]);
+
+  board.convert_edition(BoardEdition::White, BoardEdition::Black);
+  assert_eq!(board.0, [[0, 69, 69, 0, 1, 63]]);
+
+  let mut board = original.clone();
+  board.convert_edition(BoardEdition::White, BoardEdition::White);
+  assert_eq!(board, original);
+}
+
+#[test]
+fn it_keeps_invalid_codes_when_converting_editions() {
+  let mut board = BoardData([[99, 43, 0]]);
+  board.convert_edition(BoardEdition::Black, BoardEdition::White);
+  assert_eq!(board.0, [[99, 43, 70]]);
+
+  board.convert_edition(BoardEdition::White, BoardEdition::Black);
+  assert_eq!(board.0, [[99, 43, 0]]);
+}
+
+#[test]
+fn it_converts_dyn_boards_between_editions() {
+  let mut board: DynBoardData = BoardData([[0, 71]]).into();
+  board.convert_edition(BoardEdition::White, BoardEdition::Black);
+
+  assert_eq!(board.get(0, 0), Some(69));
+  assert_eq!(board.get(0, 1), Some(0));
+}
+
+#[test]
+fn it_draws_white_editions_in_terminals() {
+  let board = BoardData([[71, 1]]);
+  let options = TerminalOptions::default()
+    .border(false)
+    .compact(true)
+    .edition(BoardEdition::White);
+
+  assert_eq!(
+    board.to_terminal(&options),
+    "\x1b[48;2;0;0;0m \x1b[48;2;240;240;237m\x1b[38;2;28;28;28mA\x1b[0m\n"
+  );
+}
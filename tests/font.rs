@@ -0,0 +1,105 @@
+use vestaboard::board::{BigFont, BoardData, BoardError, CharacterCode, FlagshipBoard};
+
+const SUPPORTED: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ :.-+/°";
+
+#[test]
+fn it_measures_text() {
+  assert_eq!(BigFont::Small.height(), 3);
+  assert_eq!(BigFont::Large.height(), 5);
+
+  assert_eq!(BigFont::Large.measure("12:30").unwrap(), (5, 17));
+  assert_eq!(BigFont::Small.measure("1").unwrap(), (3, 3));
+  assert_eq!(BigFont::Large.measure("").unwrap(), (5, 0));
+  assert!(matches!(
+    BigFont::Small.measure("1!"),
+    Err(BoardError::InvalidChar { col: 1, .. })
+  ));
+}
+
+#[test]
+fn it_has_every_glyph() {
+  for font in [BigFont::Small, BigFont::Large] {
+    for c in SUPPORTED.chars().chain(SUPPORTED.to_lowercase().chars()) {
+      let (rows, cols) = font.measure(&c.to_string()).unwrap();
+      let mut board = BoardData::<5, 5>::default();
+      board
+        .draw_big_text(0, 0, &c.to_string(), font, CharacterCode::Red)
+        .unwrap();
+
+      for (row, col, code) in board.iter_cells() {
+        if code != CharacterCode::Blank {
+          assert!(row < rows && col < cols, "{c:?} drew outside of its size in {font:?}");
+        }
+      }
+    }
+  }
+}
+
+#[test]
+fn it_draws_big_text() {
+  let mut board = BoardData::<3, 11>::default();
+  board.fill(CharacterCode::Blue);
+  board
+    .draw_big_text(0, 0, "1-0", BigFont::Small, CharacterCode::White)
+    .unwrap();
+
+  let expected = ["##......###", ".#..###.#.#", "###.....###"].map(|row| {
+    row
+      .bytes()
+      .map(|dot| if dot == b'#' { 69 } else { 67 })
+      .collect::<Vec<u8>>()
+  });
+  for (row, expected) in board.0.iter().zip(expected) {
+    assert_eq!(row.to_vec(), expected);
+  }
+}
+
+#[test]
+fn it_draws_newlines_as_blanks() {
+  let mut board = BoardData::<3, 3>::default();
+  board.fill(CharacterCode::Red);
+  board
+    .draw_big_text(0, 0, "8", BigFont::Small, CharacterCode::Newline)
+    .unwrap();
+
+  assert_eq!(board, [[63, 0, 0], [0, 0, 0], [0, 0, 63]]);
+  assert!(board.invalid_cells().is_empty());
+}
+
+#[test]
+fn it_centers_big_text() {
+  let mut board = FlagshipBoard::default();
+  let (rows, cols) = BigFont::Large.measure("12:30").unwrap();
+  board
+    .draw_big_text(
+      (6 - rows) / 2,
+      (22 - cols) / 2,
+      "12:30",
+      BigFont::Large,
+      CharacterCode::Orange,
+    )
+    .unwrap();
+
+  let tiles = board
+    .iter_cells()
+    .filter(|(_, _, code)| *code == CharacterCode::Orange)
+    .collect::<Vec<_>>();
+  assert_eq!(tiles.iter().map(|(row, _, _)| *row).min(), Some(0));
+  assert_eq!(tiles.iter().map(|(_, col, _)| *col).min(), Some(2));
+  assert_eq!(tiles.iter().map(|(_, col, _)| *col).max(), Some(18));
+}
+
+#[test]
+fn it_checks_bounds_before_drawing() {
+  let mut board = BoardData::<3, 6>::default();
+
+  assert!(matches!(
+    board.draw_big_text(0, 0, "123", BigFont::Small, CharacterCode::Red),
+    Err(BoardError::OutOfBounds { .. })
+  ));
+  assert!(matches!(
+    board.draw_big_text(0, 0, "1", BigFont::Large, CharacterCode::Red),
+    Err(BoardError::OutOfBounds { .. })
+  ));
+  assert_eq!(board, BoardData::default());
+}
@@ -0,0 +1,87 @@
+use vestaboard::board::{BoardData, BoardError, DynBoardData, FlagshipBoard, NoteBoard};
+
+const GRID: &str = include_str!("./common/grid.txt");
+
+#[test]
+fn it_round_trips_grids() {
+  let board = FlagshipBoard::from_grid(GRID).expect("failed to parse grid");
+
+  assert_eq!(board.0[0][..7], [63, 64, 65, 66, 67, 68, 0]);
+  assert_eq!(board.0[2][5..10], [8, 5, 12, 12, 15]);
+  assert_eq!(board.0[3][11], 62);
+  assert_eq!(board.0[5][..3], [69, 70, 71]);
+
+  assert_eq!(board.to_grid(), GRID);
+  assert_eq!(FlagshipBoard::from_grid(&board.to_grid()).unwrap(), board);
+}
+
+#[test]
+fn it_trims_trailing_blanks() {
+  let board = BoardData([[63, 8, 9, 0], [0, 0, 0, 0]]);
+
+  assert_eq!(board.to_grid(), "{63}HI\n\n");
+}
+
+#[test]
+fn it_reads_grids_without_trailing_blank_lines() {
+  let board = BoardData([[63, 8, 9, 0], [1, 0, 0, 0], [0; 4], [0; 4]]);
+  let grid = board.to_grid();
+  let stripped = grid.trim_end_matches('\n');
+  assert_eq!(stripped, "{63}HI\nA");
+
+  assert_eq!(BoardData::<4, 4>::from_grid(stripped).unwrap(), board);
+  assert_eq!(
+    DynBoardData::from_grid(stripped, 4, 4).unwrap(),
+    DynBoardData::from(board)
+  );
+  assert_eq!(NoteBoard::from_grid("").unwrap(), NoteBoard::default());
+}
+
+#[test]
+fn it_reads_emoji_names_and_lowercase() {
+  let board = BoardData::<2, 4>::from_grid("🟥{orange}{65}a\r\n b\n").expect("failed to parse grid");
+
+  assert_eq!(board.0, [[63, 64, 65, 1], [0, 2, 0, 0]]);
+}
+
+#[test]
+fn it_round_trips_invalid_cells() {
+  let board = BoardData([[8, 43, 100], [255, 0, 0]]);
+
+  assert_eq!(board.to_grid(), "H{43}{100}\n{255}\n");
+  assert_eq!(BoardData::<2, 3>::from_grid(&board.to_grid()).unwrap(), board);
+  assert_eq!(board.invalid_cells().len(), 3);
+}
+
+#[test]
+fn it_rejects_invalid_grids() {
+  assert!(matches!(
+    NoteBoard::from_grid("A\nB\nC\nD\n"),
+    Err(BoardError::TooManyRows { row: 3, .. })
+  ));
+  assert!(matches!(
+    BoardData::<1, 2>::from_grid("ABC"),
+    Err(BoardError::TooManyCols { row: 0, col: 2, .. })
+  ));
+  assert!(matches!(
+    BoardData::<1, 4>::from_grid("A{256}"),
+    Err(BoardError::InvalidCode { row: 0, col: 1, .. })
+  ));
+  assert!(matches!(
+    BoardData::<1, 4>::from_grid("A{newline}"),
+    Err(BoardError::InvalidCode { row: 0, col: 1, .. })
+  ));
+  assert!(matches!(
+    BoardData::<1, 4>::from_grid("AB❤"),
+    Err(BoardError::InvalidChar { row: 0, col: 2, .. })
+  ));
+}
+
+#[test]
+fn it_round_trips_dyn_grids() {
+  let board = DynBoardData::from_grid("HI\n{63}\n", 2, 3).expect("failed to parse grid");
+
+  assert_eq!(board.get(0, 1), Some(9));
+  assert_eq!(board.get(1, 0), Some(63));
+  assert_eq!(board.to_grid(), "HI\n{63}\n");
+}
@@ -0,0 +1,195 @@
+use vestaboard::board::{BoardData, BoardEdition, CharacterCode, Dither, ImageError, ImageOptions};
+
+/// a `width`x`height` image of a single color
+fn solid(width: usize, height: usize, color: [u8; 3]) -> Vec<u8> {
+  color.repeat(width * height)
+}
+
+/// a `width`x`height` image whose left half is `left` and right half is `right`
+fn halves(width: usize, height: usize, left: [u8; 3], right: [u8; 3]) -> Vec<u8> {
+  (0..height)
+    .flat_map(|_| (0..width).flat_map(move |x| if x < width / 2 { left } else { right }))
+    .collect()
+}
+
+fn count(board: &BoardData<4, 8>, code: CharacterCode) -> usize {
+  board.iter().flatten().filter(|cell| **cell == code as u8).count()
+}
+
+#[test]
+fn it_maps_pixels_to_the_closest_color() {
+  let pixels = solid(16, 8, [0xd0, 0x30, 0x20]);
+  let board: BoardData<4, 8> = BoardData::from_rgb(16, 8, &pixels, &ImageOptions::default()).unwrap();
+  assert_eq!(count(&board, CharacterCode::Red), 32);
+
+  let pixels = halves(16, 8, [10, 10, 10], [250, 250, 250]);
+  let board: BoardData<4, 8> = BoardData::from_rgb(16, 8, &pixels, &ImageOptions::default()).unwrap();
+  assert_eq!(board.to_grid(), "{70}{70}{70}{70}{69}{69}{69}{69}\n".repeat(4));
+}
+
+#[test]
+fn it_scales_small_images_up() {
+  let pixels = [[0x00, 0x9a, 0x44], [0x00, 0x84, 0xd5]].concat();
+  let board: BoardData<2, 4> = BoardData::from_rgb(2, 1, &pixels, &ImageOptions::default()).unwrap();
+  assert_eq!(board.to_grid(), "{66}{66}{67}{67}\n".repeat(2));
+}
+
+#[test]
+fn it_averages_the_pixels_of_a_cell() {
+  // every cell covers one red and one yellow pixel, which average to orange
+  let pixels: Vec<u8> = (0..8)
+    .flat_map(|x| {
+      if x % 2 == 0 {
+        [0xff, 0x40, 0x00]
+      } else {
+        [0xff, 0xb0, 0x00]
+      }
+    })
+    .collect();
+  let board: BoardData<1, 4> = BoardData::from_rgb(8, 1, &pixels, &ImageOptions::default()).unwrap();
+  assert_eq!(board.to_grid(), "{64}{64}{64}{64}\n");
+}
+
+#[test]
+fn it_only_uses_the_palette() {
+  let pixels = solid(8, 4, [0xd0, 0x30, 0x20]);
+  let options = ImageOptions::default().palette([CharacterCode::White, CharacterCode::Black, CharacterCode::A]);
+  let board: BoardData<4, 8> = BoardData::from_rgb(8, 4, &pixels, &options).unwrap();
+  assert_eq!(count(&board, CharacterCode::Black), 32);
+
+  let options = ImageOptions::default().palette([CharacterCode::Filled]);
+  let board: BoardData<4, 8> = BoardData::from_rgb(8, 4, &pixels, &options).unwrap();
+  assert_eq!(count(&board, CharacterCode::Filled), 32);
+
+  let options = ImageOptions::default()
+    .palette([CharacterCode::Filled, CharacterCode::Black])
+    .edition(BoardEdition::White);
+  let board: BoardData<4, 8> = BoardData::from_rgb(8, 4, &solid(8, 4, [250, 250, 250]), &options).unwrap();
+  assert_eq!(
+    count(&board, CharacterCode::Black),
+    0,
+    "filled is black on a white board"
+  );
+}
+
+#[test]
+fn it_uses_filled_when_it_comes_first() {
+  let pixels = solid(8, 4, [250, 250, 250]);
+
+  // filled is white on the black edition, the same as the white tile of the default palette
+  let board: BoardData<4, 8> = BoardData::from_rgb(8, 4, &pixels, &ImageOptions::default()).unwrap();
+  assert_eq!(count(&board, CharacterCode::White), 32);
+
+  let mut options = ImageOptions::default();
+  options.palette.insert(0, CharacterCode::Filled);
+  let board: BoardData<4, 8> = BoardData::from_rgb(8, 4, &pixels, &options).unwrap();
+  assert_eq!(count(&board, CharacterCode::Filled), 32);
+
+  let board: BoardData<4, 8> =
+    BoardData::from_rgb(8, 4, &solid(8, 4, [5, 5, 5]), &options.edition(BoardEdition::White)).unwrap();
+  assert_eq!(count(&board, CharacterCode::Filled), 32);
+}
+
+#[test]
+fn it_dithers_between_colors() {
+  let options = ImageOptions::default().palette([CharacterCode::White, CharacterCode::Black]);
+  let pixels = solid(8, 4, [120, 120, 120]);
+
+  let board: BoardData<4, 8> = BoardData::from_rgb(8, 4, &pixels, &options).unwrap();
+  assert_eq!(count(&board, CharacterCode::Black), 32);
+
+  for dither in [Dither::Ordered, Dither::FloydSteinberg] {
+    let board: BoardData<4, 8> = BoardData::from_rgb(8, 4, &pixels, &options.clone().dither(dither)).unwrap();
+    let white = count(&board, CharacterCode::White);
+    assert!((12..=20).contains(&white), "{dither:?}: {white} white cells");
+  }
+
+  // flat colors that are on the board stay flat
+  let pixels = solid(8, 4, [0, 0, 0]);
+  for dither in [Dither::Ordered, Dither::FloydSteinberg] {
+    let board: BoardData<4, 8> = BoardData::from_rgb(8, 4, &pixels, &options.clone().dither(dither)).unwrap();
+    assert_eq!(count(&board, CharacterCode::Black), 32, "{dither:?}");
+  }
+}
+
+#[test]
+fn it_reads_ppm_images() {
+  let mut ppm = b"P6\n# a comment\n2 1\n255\n".to_vec();
+  ppm.extend([0xda, 0x29, 0x1c, 0xff, 0xff, 0xff]);
+  let board: BoardData<1, 2> = BoardData::from_ppm(&ppm, &ImageOptions::default()).unwrap();
+  assert_eq!(board.to_grid(), "{63}{69}\n");
+
+  let ppm = b"P3 2 1 15\n15 7 0  0 0 1\n";
+  let board: BoardData<1, 2> = BoardData::from_ppm(ppm, &ImageOptions::default()).unwrap();
+  assert_eq!(board.to_grid(), "{64}{70}\n");
+
+  let mut ppm = b"P6 1 1 65535\n".to_vec();
+  ppm.extend([0x00, 0x00, 0x9a, 0x00, 0x44, 0x00]);
+  let board: BoardData<1, 1> = BoardData::from_ppm(&ppm, &ImageOptions::default()).unwrap();
+  assert_eq!(board.to_grid(), "{66}\n");
+}
+
+#[test]
+fn it_rejects_invalid_images() {
+  let options = ImageOptions::default();
+  let from_rgb = |width, height, pixels: &[u8]| BoardData::<2, 2>::from_rgb(width, height, pixels, &options);
+  let from_ppm = |ppm: &[u8]| BoardData::<2, 2>::from_ppm(ppm, &options);
+
+  assert!(matches!(from_rgb(0, 4, &[]), Err(ImageError::Empty)));
+  assert!(matches!(
+    from_rgb(2, 2, &[0; 11]),
+    Err(ImageError::InvalidLength {
+      expected: 12,
+      found: 11
+    })
+  ));
+  assert!(matches!(
+    BoardData::<2, 2>::from_rgb(1, 1, &[0; 3], &ImageOptions::default().palette([CharacterCode::A])),
+    Err(ImageError::EmptyPalette)
+  ));
+
+  assert!(matches!(from_ppm(b"P5 1 1 255\n\0"), Err(ImageError::InvalidPpm(_))));
+  assert!(matches!(from_ppm(b"P6 1"), Err(ImageError::InvalidPpm(_))));
+  assert!(matches!(from_ppm(b"P6 1 1 0\n\0\0\0"), Err(ImageError::InvalidPpm(_))));
+  assert!(matches!(
+    from_ppm(b"P3 1 1 255 0 300 0"),
+    Err(ImageError::InvalidPpm(_))
+  ));
+  assert!(matches!(
+    from_ppm(b"P6 1 1 255\n\0\0"),
+    Err(ImageError::InvalidLength { expected: 3, found: 2 })
+  ));
+  assert!(matches!(from_ppm(b"P6 0 1 255\n"), Err(ImageError::Empty)));
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn it_reads_png_images() {
+  let encode = |color: png::ColorType, pixels: &[u8]| {
+    let mut png = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png, 2, 1);
+    encoder.set_color(color);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().unwrap();
+    writer.write_image_data(pixels).unwrap();
+    writer.finish().unwrap();
+    png
+  };
+
+  let png = encode(png::ColorType::Rgb, &[0xda, 0x29, 0x1c, 0x70, 0x2f, 0x8a]);
+  let board: BoardData<1, 2> = BoardData::from_png(&png, &ImageOptions::default()).unwrap();
+  assert_eq!(board.to_grid(), "{63}{68}\n");
+
+  let png = encode(png::ColorType::Rgba, &[0xff, 0xb8, 0x1c, 0xff, 0x00, 0x84, 0xd5, 0x00]);
+  let board: BoardData<1, 2> = BoardData::from_png(&png, &ImageOptions::default()).unwrap();
+  assert_eq!(board.to_grid(), "{65}{67}\n");
+
+  let png = encode(png::ColorType::Grayscale, &[0xff, 0x00]);
+  let board: BoardData<1, 2> = BoardData::from_png(&png, &ImageOptions::default()).unwrap();
+  assert_eq!(board.to_grid(), "{69}{70}\n");
+
+  assert!(matches!(
+    BoardData::<1, 2>::from_png(b"not a png", &ImageOptions::default()),
+    Err(ImageError::Png(_))
+  ));
+}
@@ -0,0 +1,118 @@
+use vestaboard::board::{BoardData, CharacterCode, Marquee, ScrollDirection};
+
+#[test]
+fn it_scrolls_horizontally_through_the_board() {
+  let board = BoardData::<1, 3>::default();
+  let frames = board.marquee("ab", &Marquee::default()).collect::<Vec<_>>();
+
+  let rows = frames.iter().map(|frame| frame.0[0]).collect::<Vec<_>>();
+  assert_eq!(rows, [[0, 0, 0], [0, 0, 1], [0, 1, 2], [1, 2, 0], [2, 0, 0], [0, 0, 0]]);
+}
+
+#[test]
+fn it_leaves_static_rows_untouched() {
+  let mut board = BoardData::<3, 4>::default();
+  board.write_text(0, 0, "NEWS").unwrap();
+  board.write_text(2, 0, "BYE").unwrap();
+
+  let frames = board
+    .marquee("hello world", &Marquee::default().rows(1..2).padding(0).step(3))
+    .collect::<Vec<_>>();
+
+  assert_eq!(frames.len(), 4);
+  assert_eq!(frames[0].0[1], [8, 5, 12, 12]);
+  // the last frame always ends with the end of the content
+  assert_eq!(frames[3].0[1], [15, 18, 12, 4]);
+  for frame in &frames {
+    assert_eq!(frame.0[0], board.0[0]);
+    assert_eq!(frame.0[2], board.0[2]);
+  }
+}
+
+#[test]
+fn it_scrolls_vertically_with_wrapped_text() {
+  let board = BoardData::<3, 5>::default();
+  let frames = board
+    .marquee("one two four", &Marquee::vertical().rows(1..3).padding(0))
+    .collect::<Vec<_>>();
+
+  assert_eq!(frames.len(), 2);
+  assert_eq!(frames[0].0[1][..3], [15, 14, 5]);
+  assert_eq!(frames[0].0[2][..3], [20, 23, 15]);
+  assert_eq!(frames[1].0[2], [6, 15, 21, 18, 0]);
+  assert_eq!(frames[1].0[0], [0; 5]);
+}
+
+#[test]
+fn it_scrolls_codes() {
+  let board = BoardData::<2, 2>::default();
+  let codes = [CharacterCode::Red, CharacterCode::Blue, CharacterCode::Green];
+
+  let frames = board
+    .marquee_codes(&codes, &Marquee::default().rows(0..1).padding(0))
+    .collect::<Vec<_>>();
+  assert_eq!(frames.len(), 2);
+  assert_eq!(frames[1].0, [[67, 66], [0, 0]]);
+
+  let frames = board
+    .marquee_codes(
+      &codes,
+      &Marquee::default().direction(ScrollDirection::Vertical).padding(0),
+    )
+    .collect::<Vec<_>>();
+  assert_eq!(frames.len(), 1);
+  assert_eq!(frames[0].0, [[63, 67], [66, 0]]);
+}
+
+#[test]
+fn it_scrolls_newlines_as_blanks() {
+  let board = BoardData::<1, 3>::default();
+  let codes = [CharacterCode::A, CharacterCode::Newline, CharacterCode::B];
+
+  let frames = board
+    .marquee_codes(&codes, &Marquee::default().padding(0))
+    .collect::<Vec<_>>();
+  assert_eq!(frames, [BoardData([[1, 0, 2]])]);
+  assert!(frames.iter().all(|frame| frame.invalid_cells().is_empty()));
+}
+
+#[test]
+fn it_keeps_the_board_for_empty_ranges() {
+  let board = BoardData::<2, 2>([[1, 2], [3, 4]]);
+
+  assert_eq!(
+    board.marquee("hi", &Marquee::default().rows(5..9)).collect::<Vec<_>>(),
+    vec![board]
+  );
+}
+
+#[test]
+fn it_saturates_huge_padding() {
+  let board = BoardData::<1, 3>::default();
+
+  for direction in [ScrollDirection::Horizontal, ScrollDirection::Vertical] {
+    let marquee = Marquee::default()
+      .direction(direction)
+      .padding(usize::MAX)
+      .step(usize::MAX);
+    let frames = board.marquee("ab", &marquee).collect::<Vec<_>>();
+    assert_eq!(frames.len(), 2);
+    assert!(frames.iter().all(|frame| *frame == board));
+  }
+}
+
+#[test]
+fn it_generates_frames_lazily() {
+  let board = BoardData::<1, 3>::default();
+
+  for direction in [ScrollDirection::Horizontal, ScrollDirection::Vertical] {
+    let marquee = Marquee::default().direction(direction).padding(usize::MAX / 4);
+    let frames = board.marquee("ab", &marquee).take(3).collect::<Vec<_>>();
+    assert_eq!(frames.len(), 3);
+  }
+
+  // padding past the board only adds blank frames, so it is clamped to the width of the board
+  let frames = board.marquee("ab", &Marquee::default().padding(usize::MAX / 4));
+  let rows = frames.map(|frame| frame.0[0]).collect::<Vec<_>>();
+  assert_eq!(rows, [[0, 0, 0], [0, 0, 1], [0, 1, 2], [1, 2, 0], [2, 0, 0], [0, 0, 0]]);
+}
@@ -0,0 +1,73 @@
+use vestaboard::board::{Align, BoardData, Corner, Justify, Pagination};
+
+type Small = BoardData<2, 8>;
+
+#[test]
+fn it_splits_text_across_pages() {
+  let pages = Small::paginate("one two three four five six", &Pagination::default());
+
+  let grids = pages.iter().map(|page| page.to_grid()).collect::<Vec<_>>();
+  assert_eq!(grids, ["ONE TWO\nTHREE\n", "FOUR\nFIVE SIX\n"]);
+}
+
+#[test]
+fn it_keeps_blank_lines() {
+  let pages = BoardData::<3, 4>::paginate("ab\n\ncd ef gh", &Pagination::default());
+
+  let grids = pages.iter().map(|page| page.to_grid()).collect::<Vec<_>>();
+  assert_eq!(grids, ["AB\n\nCD\n", "EF\nGH\n\n"]);
+
+  // the blank line counts toward the height of the text when aligning it
+  let pages = BoardData::<4, 4>::paginate("ab\n\ncd", &Pagination::default().align(Align::Bottom));
+  assert_eq!(pages[0].to_grid(), "\nAB\n\nCD\n");
+}
+
+#[test]
+fn it_keeps_words_together() {
+  let text = "alpha bravo charlie delta echo foxtrot golf hotel india";
+  let pages = Small::paginate(text, &Pagination::default());
+
+  let words = pages
+    .iter()
+    .flat_map(|page| {
+      page
+        .to_grid()
+        .split_whitespace()
+        .map(str::to_string)
+        .collect::<Vec<_>>()
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(words.join(" "), text.to_uppercase());
+}
+
+#[test]
+fn it_adds_page_indicators() {
+  let pages = Small::paginate(
+    "one two three four",
+    &Pagination::default().indicator(Corner::BottomRight),
+  );
+
+  let grids = pages.iter().map(|page| page.to_grid()).collect::<Vec<_>>();
+  assert_eq!(grids, ["ONE TWO\n     1/3\n", "THREE\n     2/3\n", "FOUR\n     3/3\n"]);
+
+  let pages = Small::paginate("hi", &Pagination::default().indicator(Corner::TopLeft));
+  assert_eq!(pages[0].to_grid(), "1/1\nHI\n");
+}
+
+#[test]
+fn it_lays_out_every_page() {
+  let pages = BoardData::<3, 7>::paginate(
+    "a b",
+    &Pagination::default().justify(Justify::Center).align(Align::Center),
+  );
+
+  assert_eq!(pages.len(), 1);
+  assert_eq!(pages[0].to_grid(), "\n  A B\n\n");
+}
+
+#[test]
+fn it_always_has_a_page() {
+  let pages = Small::paginate("", &Pagination::default());
+
+  assert_eq!(pages, vec![Small::default()]);
+}
@@ -0,0 +1,140 @@
+#![cfg(feature = "render")]
+
+use vestaboard::{
+  board::{BoardData, BoardEdition, CharacterCode, DynBoardData},
+  render::{tile_color, RenderError, RenderOptions, Rgb},
+};
+
+const RED: Rgb = Rgb(0xda, 0x29, 0x1c);
+
+#[test]
+fn it_has_colors_for_every_color_tile() {
+  for code in 63..=71 {
+    assert!(
+      tile_color(CharacterCode::try_from(code).unwrap()).is_some(),
+      "code {code}"
+    );
+  }
+  assert_eq!(tile_color(CharacterCode::Red), Some(RED));
+  assert_eq!(tile_color(CharacterCode::Black), Some(Rgb(0, 0, 0)));
+  assert_eq!(tile_color(CharacterCode::A), None);
+  assert_eq!(tile_color(CharacterCode::Blank), None);
+}
+
+#[test]
+fn it_sizes_the_image_from_the_options() {
+  let board: BoardData = BoardData::default();
+
+  let image = board.to_image(&RenderOptions::default()).unwrap();
+  assert_eq!(image.width(), 2 * 24 + 22 * 30 + 21 * 4);
+  assert_eq!(image.height(), 2 * 24 + 6 * 45 + 5 * 4);
+
+  let image = board
+    .to_image(&RenderOptions::default().cell_size(10, 20).gap(0).bezel(5))
+    .unwrap();
+  assert_eq!(image.width(), 2 * 5 + 22 * 10);
+  assert_eq!(image.height(), 2 * 5 + 6 * 20);
+  assert_eq!(
+    image.pixels().len(),
+    image.width() as usize * image.height() as usize * 3
+  );
+}
+
+#[test]
+fn it_draws_flaps_tiles_and_glyphs() {
+  let options = RenderOptions::default().cell_size(20, 30).gap(2).bezel(4);
+  let board = BoardData([[63, 0, 9]]);
+
+  let image = board.to_image(&options).unwrap();
+
+  // bezel
+  assert_eq!(image.pixel(0, 0), Some(options.bezel_color));
+  // the red tile in the top half of the first cell
+  assert_eq!(image.pixel(8, 8), Some(RED));
+  // the blank flap, lighter on top than on the bottom
+  let top = image.pixel(30, 8).unwrap();
+  let bottom = image.pixel(30, 30).unwrap();
+  assert_eq!(bottom, options.flap_color);
+  assert!(top.0 > bottom.0);
+  // the split line across the middle of the flap
+  assert_eq!(image.pixel(30, 4 + 14), Some(options.bezel_color));
+  // the "I" glyph in the third cell
+  let cell = (4 + 2 * 22..4 + 3 * 22 - 2).flat_map(|x| (4..34).map(move |y| (x, y)));
+  assert!(cell
+    .filter_map(|(x, y)| image.pixel(x, y))
+    .any(|pixel| pixel == options.text_color));
+  assert_eq!(image.pixel(image.width(), 0), None);
+}
+
+#[test]
+fn it_skips_the_split_line() {
+  let options = RenderOptions::default().cell_size(20, 30).bezel(0).split(false);
+  let image = BoardData([[63]]).to_image(&options).unwrap();
+
+  assert!((0..30).all(|y| image.pixel(10, y) == Some(RED)));
+}
+
+#[test]
+fn it_encodes_png() {
+  let board: BoardData = BoardData::centered("hello world");
+  let options = RenderOptions::default();
+
+  let png = board.to_png(&options).unwrap();
+  assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+  let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+  let mut pixels = vec![0; reader.output_buffer_size()];
+  let info = reader.next_frame(&mut pixels).unwrap();
+
+  let image = board.to_image(&options).unwrap();
+  assert_eq!((info.width, info.height), (image.width(), image.height()));
+  assert_eq!(&pixels[..info.buffer_size()], image.pixels());
+}
+
+#[test]
+fn it_renders_svg() {
+  let board = BoardData([[63, 8, 0]]);
+  let svg = board.to_svg(&RenderOptions::default().cell_size(20, 30).gap(2).bezel(4));
+
+  assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="72" height="38""#));
+  assert!(svg.contains(r##"<rect x="4" y="4" width="20" height="30" fill="#da291c"/>"##));
+  assert!(svg.contains(r##"fill="#f2f2f2""##));
+  assert!(svg.trim_end().ends_with("</svg>"));
+}
+
+#[test]
+fn it_renders_dyn_boards_the_same() {
+  let board = BoardData([[63, 8, 9], [0, 70, 62]]);
+  let dyn_board = DynBoardData::from(board.clone());
+  let options = RenderOptions::default();
+
+  assert_eq!(board.to_svg(&options), dyn_board.to_svg(&options));
+  assert_eq!(board.to_image(&options).unwrap(), dyn_board.to_image(&options).unwrap());
+}
+
+#[test]
+fn it_rejects_huge_images() {
+  let board: BoardData = BoardData::default();
+  let options = RenderOptions::default().cell_size(u32::MAX, u32::MAX);
+
+  assert!(matches!(board.to_image(&options), Err(RenderError::TooLarge { .. })));
+  assert!(matches!(board.to_png(&options), Err(RenderError::TooLarge { .. })));
+}
+
+#[test]
+fn it_renders_white_editions() {
+  let options = RenderOptions::default()
+    .cell_size(20, 30)
+    .gap(2)
+    .bezel(4)
+    .edition(BoardEdition::White);
+  let board = BoardData([[71, 0]]);
+
+  let image = board.to_image(&options).unwrap();
+
+  assert_eq!(options.bezel_color, BoardEdition::White.bezel_color());
+  assert_eq!(image.pixel(0, 0), Some(options.bezel_color));
+  // filled is black on the white edition
+  assert_eq!(image.pixel(8, 8), Some(Rgb(0, 0, 0)));
+  assert_eq!(image.pixel(30, 30), Some(BoardEdition::White.flap_color()));
+}
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use vestaboard::board::{BoardData, BoardRows, CharacterCode, CodeName, DynBoardData, NoteBoard};
+
+#[test]
+fn it_serializes_codes_as_numbers() {
+  assert_eq!(serde_json::to_string(&CharacterCode::Red).unwrap(), "63");
+  assert_eq!(serde_json::from_str::<CharacterCode>("63").unwrap(), CharacterCode::Red);
+  assert_eq!(
+    serde_json::from_str::<CharacterCode>("\"red\"").unwrap(),
+    CharacterCode::Red
+  );
+  assert!(serde_json::from_str::<CharacterCode>("43").is_err());
+  assert!(serde_json::from_str::<CharacterCode>("-1").is_err());
+  assert!(serde_json::from_str::<CharacterCode>("\"crimson\"").is_err());
+}
+
+#[test]
+fn it_names_codes() {
+  assert_eq!(CharacterCode::A.name(), "A");
+  assert_eq!(CharacterCode::One.name(), "1");
+  assert_eq!(CharacterCode::DegreeSign.name(), "degree");
+  assert_eq!("Degree".parse::<CharacterCode>().unwrap(), CharacterCode::DegreeSign);
+  assert_eq!("a".parse::<CharacterCode>().unwrap(), CharacterCode::A);
+
+  for code in (0..=u8::MAX).filter_map(|code| CharacterCode::try_from(code).ok()) {
+    assert_eq!(code.name().parse::<CharacterCode>().unwrap(), code);
+  }
+}
+
+#[serde_as]
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Config {
+  #[serde_as(as = "CodeName")]
+  accent: CharacterCode,
+  #[serde_as(as = "Vec<CodeName>")]
+  palette: Vec<CharacterCode>,
+  #[serde_as(as = "BoardRows")]
+  board: NoteBoard,
+  #[serde_as(as = "BoardRows")]
+  preview: DynBoardData,
+}
+
+#[test]
+fn it_round_trips_codes_in_binary_formats() {
+  let bytes = bincode::serialize(&CharacterCode::Red).unwrap();
+  assert_eq!(bytes, [63]);
+  assert_eq!(
+    bincode::deserialize::<CharacterCode>(&bytes).unwrap(),
+    CharacterCode::Red
+  );
+  assert!(bincode::deserialize::<CharacterCode>(&[43]).is_err());
+
+  let config = Config {
+    accent: CharacterCode::Violet,
+    palette: vec![CharacterCode::Red, CharacterCode::Blank, CharacterCode::Z],
+    board: NoteBoard::default(),
+    preview: DynBoardData::new(1, 2),
+  };
+  let bytes = bincode::serialize(&config).unwrap();
+  assert_eq!(bincode::deserialize::<Config>(&bytes).unwrap(), config);
+}
+
+#[test]
+fn it_serializes_boards_as_rows() {
+  let mut board = NoteBoard::default();
+  board.0[0][..3].copy_from_slice(&[63, 64, 65]);
+  board.0[1][..5].copy_from_slice(&[8, 9, 0, 62, 37]);
+
+  let config = Config {
+    accent: CharacterCode::Red,
+    palette: vec![CharacterCode::Blue, CharacterCode::Blank],
+    board: board.clone(),
+    preview: BoardData([[1, 71]]).into(),
+  };
+
+  let json = serde_json::to_value(&config).unwrap();
+  assert_eq!(
+    json,
+    serde_json::json!({
+      "accent": "red",
+      "palette": ["blue", "blank"],
+      "board": [
+        "{63}{64}{65}            ",
+        "HI °!          ",
+        "               ",
+      ],
+      "preview": ["A{71}"],
+    })
+  );
+
+  assert_eq!(serde_json::from_value::<Config>(json).unwrap(), config);
+}
+
+#[test]
+fn it_round_trips_invalid_cells_as_rows() {
+  let mut board = NoteBoard::default();
+  board.0[0][..2].copy_from_slice(&[43, 100]);
+
+  let config = Config {
+    accent: CharacterCode::Red,
+    palette: vec![],
+    board,
+    preview: BoardData([[8, 200]]).into(),
+  };
+
+  let json = serde_json::to_value(&config).unwrap();
+  assert_eq!(json["board"][0], "{43}{100}             ");
+  assert_eq!(json["preview"][0], "H{200}");
+  assert_eq!(serde_json::from_value::<Config>(json).unwrap(), config);
+}
+
+#[test]
+fn it_reads_loose_rows() {
+  let json = r#"{"accent": 63, "palette": [], "board": ["{red}🟧{65}", "hi", ""], "preview": ["ab", "c"]}"#;
+  let config: Config = serde_json::from_str(json).unwrap();
+
+  assert_eq!(config.board.0[0][..4], [63, 64, 65, 0]);
+  assert_eq!(config.board.0[1][..3], [8, 9, 0]);
+  assert_eq!(config.preview.cols(), 2);
+  assert_eq!(config.preview.get(1, 1), Some(0));
+}
+
+#[test]
+fn it_rejects_invalid_rows() {
+  let config = |board: &str| {
+    serde_json::from_str::<Config>(&format!(
+      r#"{{"accent": 63, "palette": [], "board": {board}, "preview": []}}"#
+    ))
+  };
+
+  assert!(config(r#"["", ""]"#).is_err());
+  assert!(config(r#"["", "", "", ""]"#).is_err());
+  assert!(config(r#"["ABCDEFGHIJKLMNOP", "", ""]"#).is_err());
+  assert!(config(r#"["{256}", "", ""]"#).is_err());
+  assert!(config(r#"["{63", "", ""]"#).is_err());
+  assert!(config(r#"["❤", "", ""]"#).is_err());
+}
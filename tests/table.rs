@@ -0,0 +1,78 @@
+use vestaboard::board::{BoardData, BoardError, CharacterCode, Column, Justify, Overflow, Table};
+
+fn departures() -> Table {
+  Table::new(vec![
+    Column::new(5),
+    Column::new(6).overflow(Overflow::Ellipsis),
+    Column::new(3).justify(Justify::Right),
+  ])
+  .header(["time", "to", "gate"])
+  .row(["09:15", "boston", "b12"])
+  .row(["09:40", "san francisco", "a3"])
+  .row(["10:05", "nyc", "c7"])
+}
+
+#[test]
+fn it_lays_out_tables() {
+  let table = departures();
+  assert_eq!(table.width(), 16);
+
+  let board = BoardData::<4, 16>::from_table(&table).unwrap();
+  assert_eq!(
+    board.to_grid(),
+    "TIME  TO     GAT\n09:15 BOSTON B12\n09:40 SAN F.  A3\n10:05 NYC     C7\n"
+  );
+}
+
+#[test]
+fn it_aligns_cells() {
+  let table = Table::new(vec![
+    Column::new(4).justify(Justify::Center),
+    Column::new(4).justify(Justify::Right),
+  ])
+  .separator(None)
+  .row(["ab", "c"])
+  .row(["a"]);
+
+  let board = BoardData::<2, 8>::from_table(&table).unwrap();
+  assert_eq!(board.to_grid(), " AB    C\n A\n");
+}
+
+#[test]
+fn it_draws_colored_separators() {
+  let table = Table::new(vec![Column::new(2), Column::new(2)])
+    .separator(Some(CharacterCode::Red))
+    .row(["1", "2"]);
+
+  let board = BoardData::<2, 5>::from_table(&table).unwrap();
+  assert_eq!(board.to_grid(), "1 {63}2\n\n");
+}
+
+#[test]
+fn it_pages_long_tables() {
+  let pages = BoardData::<3, 16>::paginate_table(&departures()).unwrap();
+
+  let grids = pages.iter().map(|page| page.to_grid()).collect::<Vec<_>>();
+  assert_eq!(
+    grids,
+    [
+      "TIME  TO     GAT\n09:15 BOSTON B12\n09:40 SAN F.  A3\n",
+      "TIME  TO     GAT\n10:05 NYC     C7\n\n",
+    ]
+  );
+
+  let empty = Table::new(vec![Column::new(3)]);
+  assert_eq!(
+    BoardData::<3, 16>::paginate_table(&empty).unwrap(),
+    vec![BoardData::default()]
+  );
+}
+
+#[test]
+fn it_rejects_wide_tables() {
+  assert!(matches!(
+    BoardData::<3, 15>::from_table(&departures()),
+    Err(BoardError::OutOfBounds { row: 0, col: 15 })
+  ));
+  assert!(BoardData::<1, 16>::paginate_table(&departures()).is_err());
+}
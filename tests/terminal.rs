@@ -0,0 +1,62 @@
+use vestaboard::board::{BoardData, DynBoardData, TerminalOptions};
+
+#[test]
+fn it_draws_plain_boards() {
+  let board = BoardData([[8, 9, 0], [63, 70, 71]]);
+
+  assert_eq!(
+    board.to_terminal(&TerminalOptions::plain()),
+    "+---------+\n| H  I    |\n| r  k  * |\n+---------+\n"
+  );
+  assert_eq!(
+    board.to_terminal(&TerminalOptions::plain().compact(true)),
+    "+---+\n|HI |\n|rk*|\n+---+\n"
+  );
+  assert_eq!(
+    board.to_terminal(&TerminalOptions::plain().compact(true).border(false)),
+    "HI \nrk*\n"
+  );
+}
+
+#[test]
+fn it_draws_fixed_width_cells() {
+  let board = BoardData([[62, 63, 64, 65, 66, 67, 68, 69, 70, 71, 1]]);
+
+  for line in board.to_terminal(&TerminalOptions::plain()).lines() {
+    assert_eq!(line.chars().count(), 11 * 3 + 2, "{line:?}");
+  }
+}
+
+#[test]
+fn it_draws_colors() {
+  let board = BoardData([[63, 1]]);
+  let out = board.to_terminal(&TerminalOptions::default().border(false).compact(true));
+
+  assert_eq!(
+    out,
+    "\x1b[48;2;218;41;28m \x1b[48;2;28;28;28m\x1b[38;2;242;242;242mA\x1b[0m\n"
+  );
+}
+
+#[test]
+fn it_draws_colored_borders() {
+  let board = BoardData([[1]]);
+  let out = board.to_terminal(&TerminalOptions::default());
+  let lines = out.lines().collect::<Vec<_>>();
+
+  assert_eq!(lines.len(), 3);
+  assert_eq!(lines[0], "\x1b[48;2;13;13;13m     \x1b[0m");
+  assert!(lines[1].starts_with("\x1b[48;2;13;13;13m "));
+  assert!(lines[1].ends_with("\x1b[48;2;13;13;13m \x1b[0m"));
+}
+
+#[test]
+fn it_draws_dyn_boards_the_same() {
+  let board = BoardData([[8, 9, 0], [63, 70, 71]]);
+  let options = TerminalOptions::default();
+
+  assert_eq!(
+    DynBoardData::from(board.clone()).to_terminal(&options),
+    board.to_terminal(&options)
+  );
+}
@@ -0,0 +1,90 @@
+use vestaboard::board::{BoardData, BoardError, CharacterCode, EdgeMode, Rect};
+
+fn board() -> BoardData<2, 3> {
+  BoardData([[1, 2, 3], [4, 5, 6]])
+}
+
+#[test]
+fn it_shifts_with_wrap() {
+  let mut shifted = board();
+  shifted.shift(0, 1, EdgeMode::Wrap);
+  assert_eq!(shifted.0, [[3, 1, 2], [6, 4, 5]]);
+
+  let mut shifted = board();
+  shifted.shift(-1, -4, EdgeMode::Wrap);
+  assert_eq!(shifted.0, [[5, 6, 4], [2, 3, 1]]);
+}
+
+#[test]
+fn it_shifts_with_fill() {
+  let mut shifted = board();
+  shifted.shift(1, -1, EdgeMode::Fill(CharacterCode::Red));
+  assert_eq!(shifted.0, [[63, 63, 63], [2, 3, 63]]);
+
+  let mut shifted = board();
+  shifted.shift(0, 10, EdgeMode::Fill(CharacterCode::Blank));
+  assert_eq!(shifted.0, [[0; 3]; 2]);
+}
+
+#[test]
+fn it_shifts_by_huge_amounts() {
+  let mut shifted = board();
+  shifted.shift(isize::MAX, isize::MIN, EdgeMode::Wrap);
+  assert_eq!(shifted.0, [[6, 4, 5], [3, 1, 2]]);
+
+  let mut shifted = board();
+  shifted.shift(0, isize::MIN, EdgeMode::Fill(CharacterCode::Blank));
+  assert_eq!(shifted.0, [[0; 3]; 2]);
+
+  let mut shifted = board();
+  shifted.shift(isize::MAX, 0, EdgeMode::Fill(CharacterCode::Blank));
+  assert_eq!(shifted.0, [[0; 3]; 2]);
+}
+
+#[test]
+fn it_flips() {
+  let mut flipped = board();
+  flipped.flip_horizontal();
+  assert_eq!(flipped.0, [[3, 2, 1], [6, 5, 4]]);
+
+  let mut flipped = board();
+  flipped.flip_vertical();
+  assert_eq!(flipped.0, [[4, 5, 6], [1, 2, 3]]);
+}
+
+#[test]
+fn it_crops() {
+  let cropped: BoardData<2, 2> = board().crop(0, 1).unwrap();
+  assert_eq!(cropped.0, [[2, 3], [5, 6]]);
+
+  assert!(matches!(
+    board().crop::<2, 2>(0, 2),
+    Err(BoardError::OutOfBounds { .. })
+  ));
+
+  let region = board().region(Rect::new(1, 0, 1, 2)).unwrap();
+  assert_eq!((region.rows(), region.cols()), (1, 2));
+  assert_eq!(region.get(0, 1), Some(5));
+  assert!(board().region(Rect::new(1, 1, 2, 1)).is_err());
+}
+
+#[test]
+fn it_embeds_and_pads() {
+  let embedded: BoardData<3, 4> = board().embed(1, 1, CharacterCode::Blue).unwrap();
+  assert_eq!(embedded.0, [[67, 67, 67, 67], [67, 1, 2, 3], [67, 4, 5, 6]]);
+  assert!(board().embed::<3, 4>(2, 0, CharacterCode::Blank).is_err());
+
+  let padded: BoardData<4, 6> = board().pad(CharacterCode::Blank).unwrap();
+  assert_eq!(padded.0[1], [0, 1, 2, 3, 0, 0]);
+  assert_eq!(padded.0[2], [0, 4, 5, 6, 0, 0]);
+  assert!(board().pad::<1, 3>(CharacterCode::Blank).is_err());
+}
+
+#[test]
+fn it_iterates_cells_with_positions() {
+  let cells = board().iter_cells().collect::<Vec<_>>();
+
+  assert_eq!(cells.len(), 6);
+  assert_eq!(cells[0], (0, 0, CharacterCode::A));
+  assert_eq!(cells[4], (1, 1, CharacterCode::E));
+}
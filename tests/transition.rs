@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+use vestaboard::board::{BoardData, CharacterCode, DEFAULT_FLAP_DURATION, FLAP_ORDER};
+
+#[test]
+fn it_follows_the_flap_order() {
+  assert_eq!(FLAP_ORDER[0], CharacterCode::Blank);
+  assert_eq!(FLAP_ORDER[1], CharacterCode::A);
+  assert_eq!(FLAP_ORDER[FLAP_ORDER.len() - 1], CharacterCode::Filled);
+}
+
+#[test]
+fn it_counts_flaps() {
+  let from = BoardData([[0, 1, 3]]);
+  let to = BoardData([[3, 1, 1]]);
+
+  let transition = from.transition_to(&to);
+  // C -> A wraps all the way around the wheel
+  assert_eq!(transition.cell_flaps(), &[[3, 0, FLAP_ORDER.len() - 2]]);
+  assert_eq!(transition.flap_count(), 3 + FLAP_ORDER.len() - 2);
+  assert_eq!(transition.moving_cells(), 2);
+  assert_eq!(transition.steps(), FLAP_ORDER.len() - 2);
+  assert_eq!(
+    transition.estimated_duration(),
+    DEFAULT_FLAP_DURATION * (FLAP_ORDER.len() - 2) as u32
+  );
+}
+
+#[test]
+fn it_produces_frames() {
+  let from = BoardData([[0, 2]]);
+  let to = BoardData([[2, 2]]);
+
+  let frames = from.transition_to(&to).frames().collect::<Vec<_>>();
+  assert_eq!(
+    frames,
+    vec![BoardData([[0, 2]]), BoardData([[1, 2]]), BoardData([[2, 2]])]
+  );
+}
+
+#[test]
+fn it_flips_colors_past_the_characters() {
+  let from = BoardData([[CharacterCode::DegreeSign as u8]]);
+  let to = BoardData([[CharacterCode::Orange as u8]]);
+
+  let transition = from.transition_to(&to).with_flap_duration(Duration::from_millis(100));
+  assert_eq!(transition.frame(1), BoardData([[CharacterCode::Red as u8]]));
+  assert_eq!(transition.frame(10), to);
+  assert_eq!(transition.estimated_duration(), Duration::from_millis(200));
+}
+
+#[test]
+fn it_settles_immediately_without_changes() {
+  let board = BoardData([[1, 2], [3, 4]]);
+
+  let transition = board.transition_to(&board);
+  assert_eq!(transition.steps(), 0);
+  assert_eq!(transition.estimated_duration(), Duration::ZERO);
+  assert_eq!(transition.frames().collect::<Vec<_>>(), vec![board]);
+}
@@ -0,0 +1,86 @@
+use vestaboard::board::{transliterate, BoardData, CharacterCode, Replacement, TextLayout};
+
+#[test]
+fn it_keeps_board_characters() {
+  let result = transliterate("Hello, World! 20° 🟥\n");
+
+  assert_eq!(result.text, "HELLO, WORLD! 20° 🟥\n");
+  assert!(result.is_exact());
+}
+
+#[test]
+fn it_folds_accents() {
+  assert_eq!(transliterate("café naïve Ørsted").text, "CAFE NAIVE ORSTED");
+  assert_eq!(transliterate("Straße").text, "STRASSE");
+  assert_eq!(transliterate("Æsir").text, "AESIR");
+}
+
+#[test]
+fn it_maps_smart_punctuation() {
+  assert_eq!(transliterate("“don’t” — wait…").text, "\"DON'T\" - WAIT...");
+  assert_eq!(transliterate("a\u{a0}b\tc").text, "A B C");
+}
+
+#[test]
+fn it_spells_out_symbols() {
+  assert_eq!(transliterate("2×3").text, "2X3");
+  assert_eq!(transliterate("€5 ½ ©").text, "EUR5 1/2 (C)");
+  assert_eq!(transliterate("🔴🔵").text, "🟥🟦");
+}
+
+#[test]
+fn it_reports_replacements() {
+  let result = transliterate("é❤x™");
+
+  assert_eq!(result.text, "EXTM");
+  assert_eq!(
+    result.replacements,
+    vec![
+      Replacement::Substituted {
+        index: 0,
+        original: 'é',
+        replacement: "E",
+      },
+      Replacement::Dropped {
+        index: 2,
+        original: '❤',
+      },
+      Replacement::Substituted {
+        index: 6,
+        original: '™',
+        replacement: "TM",
+      },
+    ]
+  );
+  assert_eq!(result.dropped().map(Replacement::original).collect::<String>(), "❤");
+  assert_eq!(result.substituted().count(), 2);
+  assert!(!result.is_exact());
+}
+
+#[test]
+fn it_removes_invisible_characters_silently() {
+  let result = transliterate("a\r\nb\u{200b}c⬛\u{fe0f}");
+
+  assert_eq!(result.text, "A\nBC⬛");
+  assert!(result.is_exact());
+}
+
+#[test]
+fn it_transliterates_single_characters() {
+  assert_eq!(CharacterCode::from_char_lossy('é'), CharacterCode::E);
+  assert_eq!(CharacterCode::from_char_lossy('’'), CharacterCode::SingleQuote);
+  assert_eq!(CharacterCode::from_char_lossy('×'), CharacterCode::X);
+  // spelled out symbols need more than one cell
+  assert_eq!(CharacterCode::from_char_lossy('€'), CharacterCode::Blank);
+  assert_eq!(CharacterCode::from_char_lossy('❤'), CharacterCode::Blank);
+}
+
+#[test]
+fn it_transliterates_board_text() {
+  let mut board = BoardData([[0; 6]; 1]);
+  board.write_text(0, 0, "café").unwrap();
+  assert_eq!(board, [[3, 1, 6, 5, 0, 0]]);
+
+  let board: BoardData<1, 6> = BoardData::from_text("€1", TextLayout::default());
+  assert_eq!(board, [[5, 21, 18, 27, 0, 0]]);
+}
@@ -0,0 +1,87 @@
+use vestaboard::board::{
+  BoardData, BoardError, CharacterCode, CharacterCodeError, CodeHandling, DynBoardData, InvalidCell, NoteBoard,
+};
+
+#[test]
+fn it_converts_codes_strictly() {
+  assert_eq!(CharacterCode::try_from(1), Ok(CharacterCode::A));
+  assert_eq!(CharacterCode::try_from(71), Ok(CharacterCode::Filled));
+  assert_eq!(CharacterCode::try_from(43), Err(CharacterCodeError::InvalidCode(43)));
+  assert_eq!(CharacterCode::try_from(72), Err(CharacterCodeError::InvalidCode(72)));
+
+  assert_eq!(CharacterCode::from_u8_lossy(43), CharacterCode::Blank);
+  assert!(CharacterCode::is_cell_code(0));
+  assert!(!CharacterCode::is_cell_code(43));
+}
+
+#[test]
+fn it_converts_chars_strictly() {
+  assert_eq!(CharacterCode::try_from('a'), Ok(CharacterCode::A));
+  assert_eq!(CharacterCode::try_from('°'), Ok(CharacterCode::DegreeSign));
+  assert_eq!(CharacterCode::try_from('é'), Err(CharacterCodeError::InvalidChar('é')));
+
+  assert_eq!(CharacterCode::from_char_lossy('é'), CharacterCode::E);
+  assert_eq!(CharacterCode::from_char_lossy('漢'), CharacterCode::Blank);
+}
+
+#[test]
+fn it_validates_boards() {
+  let mut board = NoteBoard::default();
+  board.0[0][0] = 43;
+  board.0[2][5] = 200;
+
+  let cells = vec![
+    InvalidCell {
+      row: 0,
+      col: 0,
+      code: 43,
+    },
+    InvalidCell {
+      row: 2,
+      col: 5,
+      code: 200,
+    },
+  ];
+  assert_eq!(board.invalid_cells(), cells);
+  assert!(matches!(board.validate(), Err(BoardError::InvalidCells(found)) if found == cells));
+
+  board.sanitize();
+  assert!(board.validate().is_ok());
+  assert_eq!(board.0[2][5], 0);
+}
+
+#[test]
+fn it_validates_dyn_boards() {
+  let mut board = DynBoardData::new(2, 3);
+  board.set(1, 2, 99).expect("failed to set cell");
+
+  assert_eq!(
+    board.invalid_cells(),
+    vec![InvalidCell {
+      row: 1,
+      col: 2,
+      code: 99
+    }]
+  );
+
+  board.sanitize();
+  assert!(board.validate().is_ok());
+}
+
+#[test]
+fn it_parses_strictly_or_lossily() {
+  let data = "[[43,1,2],[3,4,5]]";
+
+  assert!(matches!(
+    BoardData::<2, 3>::parse_with(data, CodeHandling::Strict),
+    Err(BoardError::InvalidCode { row: 0, col: 0, .. })
+  ));
+  assert!(data.parse::<BoardData<2, 3>>().is_err());
+
+  let board = BoardData::<2, 3>::parse_with(data, CodeHandling::Lossy).expect("failed to parse board");
+  assert_eq!(board.0, [[0, 1, 2], [3, 4, 5]]);
+
+  let board = DynBoardData::parse_with(data, CodeHandling::Lossy).expect("failed to parse board");
+  assert_eq!(board.get(0, 0), Some(0));
+  assert!(DynBoardData::parse_with(data, CodeHandling::Strict).is_err());
+}
@@ -4,8 +4,8 @@ use libtest_mimic::Trial;
 use serde::Deserialize;
 #[cfg(feature = "parser")]
 use vestaboard::{
-  board::{BoardData, FLAGSHIP_COLS, FLAGSHIP_ROWS},
-  vbml::Vbml,
+  board::{BoardData, BoardModel, CodeHandling, DynBoardData, NoteBoard, Replacement, FLAGSHIP_COLS, FLAGSHIP_ROWS},
+  vbml::{NoteVbml, Vbml, VbmlDiagnostic, VbmlDiagnosticKind, VbmlError},
 };
 
 #[cfg(feature = "parser")]
@@ -19,8 +19,18 @@ struct Template<const ROWS: usize, const COLS: usize> {
   expect: BoardData<ROWS, COLS>,
 }
 
+#[derive(Deserialize)]
+#[cfg(feature = "parser")]
+struct DynTemplate {
+  name: String,
+  data: Vbml,
+  expect: DynBoardData,
+}
+
 #[cfg(feature = "parser")]
 const TEMPLATES: &str = include_str!("./common/vbml.json");
+#[cfg(feature = "parser")]
+const DYN_SIZE_TEMPLATES: &str = include_str!("./common/dyn-size.json");
 
 #[cfg(feature = "parser")]
 fn test_vbml_parse<const ROWS: usize, const COLS: usize>(
@@ -38,6 +48,289 @@ fn test_vbml_parse<const ROWS: usize, const COLS: usize>(
   }
 }
 
+#[cfg(feature = "parser")]
+fn test_vbml_parse_dyn(template: DynTemplate) -> Result<(), libtest_mimic::Failed> {
+  let parsed = template.data.parse_dyn().expect("failed to parse vbml");
+
+  if parsed == template.expect {
+    Ok(())
+  } else {
+    Err(libtest_mimic::Failed::from(format!(
+      "expected:\n{}\ngot:\n{}",
+      template.expect, parsed
+    )))
+  }
+}
+
+#[cfg(feature = "parser")]
+fn test_vbml_wrapping() -> Result<(), libtest_mimic::Failed> {
+  // a word that exactly fills the rest of a row is wrapped instead of being glued to the word before it
+  let cases: [(&str, [[u8; 8]; 2]); 3] = [
+    ("ABC DEFGH", [[1, 2, 3, 0, 0, 0, 0, 0], [4, 5, 6, 7, 8, 0, 0, 0]]),
+    (
+      "HELLO WORLD FOO",
+      [[8, 5, 12, 12, 15, 0, 0, 0], [23, 15, 18, 12, 4, 0, 0, 0]],
+    ),
+    ("AB CDE FG", [[1, 2, 0, 3, 4, 5, 0, 0], [6, 7, 0, 0, 0, 0, 0, 0]]),
+  ];
+
+  for (template, expected) in cases {
+    let string = serde_json::json!({ "components": [{ "template": template }] }).to_string();
+    let vbml: Vbml<2, 8> = string.parse().expect("failed to deserialize vbml");
+    let parsed = vbml.parse().expect("failed to parse vbml");
+
+    if parsed.0 != expected {
+      return Err(libtest_mimic::Failed::from(format!(
+        "{template:?}: expected:\n{expected:?}\ngot:\n{parsed}"
+      )));
+    }
+  }
+
+  // "ROCKS" exactly fills the rest of the first row of a flagship board after "VESTABOARD MARKUP", so it
+  // would need a space and is wrapped
+  let string = r#"{"components":[{"style":{"justify":"left","align":"top"},"template":"Vestaboard Markup Rocks"}]}"#;
+  let vbml: Vbml<FLAGSHIP_ROWS, FLAGSHIP_COLS> = string.parse().expect("failed to deserialize vbml");
+  let parsed = vbml.parse().expect("failed to parse vbml");
+
+  let expected: [&[u8]; 2] = [
+    &[22, 5, 19, 20, 1, 2, 15, 1, 18, 4, 0, 13, 1, 18, 11, 21, 16],
+    &[18, 15, 3, 11, 19],
+  ];
+  let filled = parsed.0[..2]
+    .iter()
+    .zip(expected)
+    .all(|(row, expected)| row.starts_with(expected) && row[expected.len()..].iter().all(|code| *code == 0));
+  if !filled || parsed.0[2..].iter().flatten().any(|code| *code != 0) {
+    return Err(libtest_mimic::Failed::from(format!(
+      "expected:\n{expected:?}\ngot:\n{parsed}"
+    )));
+  }
+
+  Ok(())
+}
+
+#[cfg(feature = "parser")]
+fn test_vbml_blank_lines() -> Result<(), libtest_mimic::Failed> {
+  // blank lines are collapsed, the same as the JS parser
+  let string = serde_json::json!({ "components": [{ "template": "ab\n\ncd" }] }).to_string();
+  let vbml: Vbml<4, 6> = string.parse().expect("failed to deserialize vbml");
+  let parsed = vbml.parse().expect("failed to parse vbml");
+
+  let expected = [[1, 2, 0, 0, 0, 0], [3, 4, 0, 0, 0, 0], [0; 6], [0; 6]];
+  if parsed.0 == expected {
+    Ok(())
+  } else {
+    Err(libtest_mimic::Failed::from(format!(
+      "expected:\n{expected:?}\ngot:\n{parsed}"
+    )))
+  }
+}
+
+#[cfg(feature = "parser")]
+fn test_vbml_parse_model() -> Result<(), libtest_mimic::Failed> {
+  let string = r#"{"components":[{"style":{"justify":"center","align":"center"},"template":"hello note"}]}"#;
+
+  let note: NoteVbml = string.parse().expect("failed to deserialize vbml");
+  let parsed: NoteBoard = note.parse().expect("failed to parse vbml");
+
+  let vbml: Vbml = string.parse().expect("failed to deserialize vbml");
+  let model: BoardModel = "note".parse().expect("failed to parse model");
+  let by_name = vbml.parse_model(model).expect("failed to parse vbml");
+
+  if by_name == DynBoardData::from(parsed.clone()) && parsed.0[1][2..12] == [8, 5, 12, 12, 15, 0, 14, 15, 20, 5] {
+    Ok(())
+  } else {
+    Err(libtest_mimic::Failed::from(format!(
+      "expected:\n{}\ngot:\n{}",
+      parsed, by_name
+    )))
+  }
+}
+
+#[cfg(feature = "parser")]
+fn test_vbml_transliterate() -> Result<(), libtest_mimic::Failed> {
+  let string = r#"{"props":{"name":"Zoë"},"components":[{"template":"“{{name}}’s” café"}]}"#;
+
+  let vbml: NoteVbml = string.parse().expect("failed to deserialize vbml");
+  let parsed: NoteBoard = vbml.parse().expect("failed to parse vbml");
+
+  // "ZOE'S" CAFE
+  let expected = [53, 26, 15, 5, 52, 19, 53, 0, 3, 1, 6, 5];
+  if parsed.0[0][..12] == expected {
+    Ok(())
+  } else {
+    Err(libtest_mimic::Failed::from(format!(
+      "expected:\n{:?}\ngot:\n{}",
+      expected, parsed
+    )))
+  }
+}
+
+#[cfg(feature = "parser")]
+fn test_vbml_filled() -> Result<(), libtest_mimic::Failed> {
+  let string = r#"{"props":{"tile":"{71}"},"components":[{"template":"{71}{69}{{tile}}"}]}"#;
+
+  let vbml: NoteVbml = string.parse().expect("failed to deserialize vbml");
+  let parsed: NoteBoard = vbml.parse().expect("failed to parse vbml");
+
+  // filled stays filled instead of becoming a white tile
+  let expected = [71, 69, 71];
+  if parsed.0[0][..3] == expected {
+    Ok(())
+  } else {
+    Err(libtest_mimic::Failed::from(format!(
+      "expected:\n{:?}\ngot:\n{}",
+      expected, parsed
+    )))
+  }
+}
+
+#[cfg(feature = "parser")]
+fn test_vbml_strict_codes() -> Result<(), libtest_mimic::Failed> {
+  let string = r#"{"components":[{"template":"ok"},{"template":"{43} ❤"}]}"#;
+  let vbml: NoteVbml = string.parse().expect("failed to deserialize vbml");
+
+  let lossy: NoteBoard = vbml.parse().expect("failed to parse vbml");
+  if !lossy.invalid_cells().is_empty() {
+    return Err(libtest_mimic::Failed::from(format!(
+      "lossy parse kept invalid cells:\n{lossy}"
+    )));
+  }
+
+  match vbml.parser().codes(CodeHandling::Strict).parse() {
+    Err(VbmlError::InvalidCode { component: 1, code }) if code == "43" => {}
+    other => {
+      return Err(libtest_mimic::Failed::from(format!(
+        "expected invalid code, got {other:?}"
+      )))
+    }
+  }
+
+  let string = r#"{"components":[{"template":"I ❤ YOU"}]}"#;
+  let vbml: NoteVbml = string.parse().expect("failed to deserialize vbml");
+  match vbml.parser().codes(CodeHandling::Strict).parse() {
+    Err(VbmlError::InvalidChar {
+      component: 0,
+      found: '❤',
+    }) => Ok(()),
+    other => Err(libtest_mimic::Failed::from(format!(
+      "expected invalid char, got {other:?}"
+    ))),
+  }
+}
+
+#[cfg(feature = "parser")]
+fn test_vbml_diagnostics() -> Result<(), libtest_mimic::Failed> {
+  let string = r#"{"components":[
+    {"style":{"height":1,"width":5},"template":"hello world"},
+    {"style":{"height":1,"width":5,"absolutePosition":{"x":3,"y":0}},"template":"abcde"},
+    {"style":{"height":1,"width":4,"absolutePosition":{"x":13,"y":2}},"template":"{{who}}okay"},
+    {"style":{"height":1,"width":5},"template":"café {43}"}
+  ]}"#;
+  let vbml: NoteVbml = string.parse().expect("failed to deserialize vbml");
+
+  let (board, diagnostics) = vbml.parse_with_diagnostics().expect("failed to parse vbml");
+  let diagnostic = |component, row, col, kind| VbmlDiagnostic {
+    component,
+    row,
+    col,
+    kind,
+  };
+  let expected = vec![
+    diagnostic(0, 0, 0, VbmlDiagnosticKind::TruncatedText),
+    diagnostic(3, 0, 5, VbmlDiagnosticKind::InvalidCode { code: "43".to_string() }),
+    diagnostic(
+      3,
+      0,
+      5,
+      VbmlDiagnosticKind::Replaced(Replacement::Substituted {
+        index: 3,
+        original: 'é',
+        replacement: "E",
+      }),
+    ),
+    diagnostic(1, 0, 3, VbmlDiagnosticKind::Overlap { other: 0, count: 2 }),
+    diagnostic(1, 0, 3, VbmlDiagnosticKind::Overlap { other: 3, count: 3 }),
+    diagnostic(
+      2,
+      2,
+      13,
+      VbmlDiagnosticKind::UnknownProp {
+        name: "who".to_string(),
+      },
+    ),
+    diagnostic(2, 2, 13, VbmlDiagnosticKind::ClippedCells { count: 2 }),
+  ];
+  if diagnostics != expected {
+    return Err(libtest_mimic::Failed::from(format!(
+      "expected:\n{expected:#?}\ngot:\n{diagnostics:#?}"
+    )));
+  }
+
+  if board.to_grid() != "HELABCDEE\n\n             OK\n" || board != vbml.parse().expect("failed to parse vbml") {
+    return Err(libtest_mimic::Failed::from(format!("unexpected board:\n{board}")));
+  }
+
+  match diagnostics[6].to_string().as_str() {
+    "component 2 at (2, 13): 2 cells are outside the board" => Ok(()),
+    other => Err(libtest_mimic::Failed::from(format!("unexpected message: {other}"))),
+  }
+}
+
+#[cfg(feature = "parser")]
+fn test_vbml_strict_layout() -> Result<(), libtest_mimic::Failed> {
+  let strict = |string: &str| {
+    let vbml: NoteVbml = string.parse().expect("failed to deserialize vbml");
+    vbml.parse().expect("lenient parse failed");
+    vbml.parser().strict().parse()
+  };
+
+  let valid = r#"{"props":{"name":"ada"},"components":[{"style":{"height":1,"width":8},"template":"hi {{name}}"}]}"#;
+  let vbml: NoteVbml = valid.parse().expect("failed to deserialize vbml");
+  match vbml.parser().strict().parse() {
+    Ok(board) if board == vbml.parse().expect("failed to parse vbml") => {}
+    other => return Err(libtest_mimic::Failed::from(format!("expected a board, got {other:?}"))),
+  }
+
+  match strict(r#"{"components":[{"style":{"height":1,"width":5},"template":"hello world"}]}"#) {
+    Err(VbmlError::Overflow { component: 0 }) => {}
+    other => return Err(libtest_mimic::Failed::from(format!("expected overflow, got {other:?}"))),
+  }
+
+  let outside = r#"{"components":[
+    {"style":{"height":1,"width":5},"template":"ok"},
+    {"style":{"height":1,"width":5,"absolutePosition":{"x":12,"y":0}},"template":"hi"}
+  ]}"#;
+  match strict(outside) {
+    Err(VbmlError::OutOfBounds {
+      component: 1,
+      row: 0,
+      col: 12,
+    }) => {}
+    other => {
+      return Err(libtest_mimic::Failed::from(format!(
+        "expected out of bounds, got {other:?}"
+      )))
+    }
+  }
+
+  match strict(r#"{"components":[{"template":"hi {{name}}"}]}"#) {
+    Err(VbmlError::MissingProp { component: 0, name }) if name == "name" => {}
+    other => {
+      return Err(libtest_mimic::Failed::from(format!(
+        "expected missing prop, got {other:?}"
+      )))
+    }
+  }
+
+  match strict(r#"{"components":[{"template":"{43}"}]}"#) {
+    Err(VbmlError::InvalidCode { component: 0, .. }) => Ok(()),
+    other => Err(libtest_mimic::Failed::from(format!(
+      "expected invalid code, got {other:?}"
+    ))),
+  }
+}
+
 #[cfg(feature = "parser")]
 fn main() {
   common::setup();
@@ -45,9 +338,25 @@ fn main() {
   let templates: Vec<Template<FLAGSHIP_ROWS, FLAGSHIP_COLS>> =
     serde_json::from_str(TEMPLATES).expect("failed to get templates");
 
+  let dyn_size_templates: Vec<DynTemplate> =
+    serde_json::from_str(DYN_SIZE_TEMPLATES).expect("failed to get dyn size templates");
+
   let tests = templates
     .into_iter()
     .map(|t| Trial::test(format!("parse::{}", &t.name), || test_vbml_parse(t)))
+    .chain(
+      dyn_size_templates
+        .into_iter()
+        .map(|t| Trial::test(format!("parse_dyn::{}", &t.name), || test_vbml_parse_dyn(t))),
+    )
+    .chain(std::iter::once(Trial::test("wrapping", test_vbml_wrapping)))
+    .chain(std::iter::once(Trial::test("blank_lines", test_vbml_blank_lines)))
+    .chain(std::iter::once(Trial::test("parse_model::Note", test_vbml_parse_model)))
+    .chain(std::iter::once(Trial::test("transliterate", test_vbml_transliterate)))
+    .chain(std::iter::once(Trial::test("filled", test_vbml_filled)))
+    .chain(std::iter::once(Trial::test("strict_codes", test_vbml_strict_codes)))
+    .chain(std::iter::once(Trial::test("diagnostics", test_vbml_diagnostics)))
+    .chain(std::iter::once(Trial::test("strict_layout", test_vbml_strict_layout)))
     .collect::<Vec<_>>();
 
   let args = libtest_mimic::Arguments::from_args();
@@ -0,0 +1,213 @@
+#![cfg(feature = "parser")]
+
+use std::collections::HashMap;
+
+use proptest::{collection::vec, option, prelude::*};
+use serde_json::{json, Value};
+use vestaboard::{
+  board::{BoardModel, CharacterCode, Replacement},
+  vbml::{Vbml, VbmlComponent, VbmlDiagnosticKind, VbmlError, MAX_SIZE},
+};
+
+/// a height, width, or position that is usually near the size of a board, and sometimes huge
+fn size() -> impl Strategy<Value = u32> {
+  prop_oneof![4 => 0u32..30, 1 => any::<u32>()]
+}
+
+/// template text with codes, props, and characters that are not on the board
+fn text() -> impl Strategy<Value = String> {
+  "(\\{[0-9]{1,4}\\}|\\{\\{[a-c]{1,2}\\}\\}|[A-Za-z0-9 .,!'\\n{}]|é|❤|—){0,60}"
+}
+
+fn style() -> impl Strategy<Value = Value> {
+  let justify = option::of(prop_oneof![
+    Just("center"),
+    Just("left"),
+    Just("right"),
+    Just("justified")
+  ]);
+  let align = option::of(prop_oneof![
+    Just("center"),
+    Just("top"),
+    Just("bottom"),
+    Just("justified"),
+    Just("absolute")
+  ]);
+  let position = option::of((size(), size()).prop_map(|(x, y)| json!({ "x": x, "y": y })));
+
+  (justify, align, option::of(size()), option::of(size()), position).prop_map(
+    |(justify, align, height, width, position)| {
+      json!({ "justify": justify, "align": align, "height": height, "width": width, "absolutePosition": position })
+    },
+  )
+}
+
+fn component() -> impl Strategy<Value = Value> {
+  let template = (style(), text()).prop_map(|(style, template)| json!({ "style": style, "template": template }));
+  let raw = (style(), 0usize..10, 0usize..30).prop_flat_map(|(style, rows, cols)| {
+    vec(vec(any::<u8>(), cols), rows).prop_map(move |raw| json!({ "style": style, "rawCharacters": raw }))
+  });
+
+  prop_oneof![4 => template, 1 => raw]
+}
+
+fn props() -> impl Strategy<Value = Option<HashMap<String, String>>> {
+  option::of(proptest::collection::hash_map("[a-c]{1,2}", text(), 0..4))
+}
+
+fn vbml() -> impl Strategy<Value = Vbml> {
+  let props = props();
+  let style = option::of(
+    (option::of(size()), option::of(size())).prop_map(|(height, width)| json!({ "height": height, "width": width })),
+  );
+
+  (props, style, vec(component(), 0..6)).prop_map(|(props, style, components)| {
+    serde_json::from_value(json!({ "props": props, "style": style, "components": components }))
+      .expect("failed to deserialize generated vbml")
+  })
+}
+
+proptest! {
+  #[test]
+  fn it_parses_any_vbml_without_panicking(vbml in vbml()) {
+    let _ = vbml.parser().parse_dyn_with_diagnostics();
+    let _ = vbml.parse_model(BoardModel::Note);
+    let strict = vbml.parser().strict().parse();
+
+    match vbml.parse_with_diagnostics() {
+      Ok((board, diagnostics)) => {
+        prop_assert!(board.invalid_cells().is_empty());
+        prop_assert_eq!(&board, &vbml.parse().expect("lenient parse failed"));
+        prop_assert!(diagnostics.iter().all(|diagnostic| diagnostic.component < vbml.components.len()));
+
+        if let Ok(strict) = strict {
+          prop_assert_eq!(strict, board);
+          for diagnostic in &diagnostics {
+            prop_assert!(
+              !matches!(
+                diagnostic.kind,
+                VbmlDiagnosticKind::TruncatedText
+                  | VbmlDiagnosticKind::ClippedCells { .. }
+                  | VbmlDiagnosticKind::UnknownProp { .. }
+                  | VbmlDiagnosticKind::InvalidCode { .. }
+                  | VbmlDiagnosticKind::Replaced(Replacement::Dropped { .. })
+              ),
+              "strict parse allowed {}", diagnostic
+            );
+          }
+        }
+      }
+      Err(error) => {
+        prop_assert!(matches!(error, VbmlError::TooLarge { .. }), "unexpected error: {}", error);
+        prop_assert!(strict.is_err());
+      }
+    }
+  }
+
+  #[test]
+  fn it_lays_out_any_component_without_panicking(component in component(), props in props()) {
+    let component: VbmlComponent<6, 22> = serde_json::from_value(component).expect("failed to deserialize component");
+
+    let (height, width, content) = component.get_word_rows(props.as_ref());
+    if let Some(content) = content {
+      prop_assert!(height <= MAX_SIZE.max(6) && width <= MAX_SIZE.max(22));
+      prop_assert!(content.iter().all(|row| row.len() <= MAX_SIZE.max(22)));
+    }
+  }
+
+  #[test]
+  fn it_deserializes_any_string_without_panicking(string in "\\PC*") {
+    let _ = string.parse::<Vbml>();
+  }
+
+  #[test]
+  fn it_replaces_any_code_in_templates(code in any::<u32>()) {
+    let string = json!({
+      "props": { "a": format!("{{{code}}}") },
+      "components": [{ "template": format!("{{{code}}}{{{{a}}}}") }],
+    })
+    .to_string();
+    let vbml: Vbml = string.parse().expect("failed to deserialize vbml");
+
+    let (board, _) = vbml.parse_with_diagnostics().expect("failed to parse vbml");
+    prop_assert!(board.invalid_cells().is_empty());
+  }
+}
+
+#[test]
+fn it_reports_content_larger_than_its_component() {
+  // the text is taller and the component is wider than the board, and the text is aligned to the bottom right
+  let string = r#"{"components":[{"style":{"height":1,"width":40,"align":"bottom","justify":"right"},"template":"a very long line of text that wraps onto a second row"}]}"#;
+  let vbml: Vbml = string.parse().expect("failed to deserialize vbml");
+
+  let (_, diagnostics) = vbml.parse_with_diagnostics().expect("failed to parse vbml");
+  assert!(diagnostics
+    .iter()
+    .any(|diagnostic| diagnostic.kind == VbmlDiagnosticKind::TruncatedText));
+  assert!(matches!(
+    vbml.parser().strict().parse(),
+    Err(VbmlError::OutOfBounds { component: 0, .. })
+  ));
+
+  let string = r#"{"components":[{"style":{"height":20,"width":3,"align":"center"},"template":"hi there"}]}"#;
+  let vbml: Vbml = string.parse().expect("failed to deserialize vbml");
+  assert_eq!(vbml.parse().expect("failed to parse vbml").to_grid(), "\n\n\n\n\n\n");
+}
+
+#[test]
+fn it_rejects_sizes_past_the_maximum() {
+  let string = format!(
+    r#"{{"components":[{{"style":{{"width":{}}},"template":"hi"}}]}}"#,
+    MAX_SIZE + 1
+  );
+  let vbml: Vbml = string.parse().expect("failed to deserialize vbml");
+  assert!(matches!(
+    vbml.parse(),
+    Err(VbmlError::TooLarge {
+      component: Some(0),
+      height: 6,
+      ..
+    })
+  ));
+
+  let component: VbmlComponent<6, 22> =
+    serde_json::from_str(r#"{"style":{"height":4000000000,"width":22},"template":"hi"}"#)
+      .expect("failed to deserialize component");
+  let (height, _, content) = component.get_word_rows(None);
+  assert_eq!(height, 1);
+  assert_eq!(
+    content.expect("template has no content")[0],
+    [CharacterCode::H, CharacterCode::I]
+  );
+
+  let string = r#"{"style":{"height":4294967295,"width":4294967295},"components":[]}"#;
+  let vbml: Vbml = string.parse().expect("failed to deserialize vbml");
+  assert!(matches!(
+    vbml.parse_dyn(),
+    Err(VbmlError::TooLarge { component: None, .. })
+  ));
+}
+
+#[test]
+fn it_parses_positions_at_the_end_of_u32() {
+  let far = json!({ "x": u32::MAX, "y": u32::MAX });
+  let string = json!({
+    "components": [
+      { "style": { "width": MAX_SIZE, "height": MAX_SIZE, "absolutePosition": far }, "template": "hi" },
+      { "style": { "width": 1, "height": 1, "absolutePosition": far }, "template": "a" },
+    ],
+  })
+  .to_string();
+  let vbml: Vbml = string.parse().expect("failed to deserialize vbml");
+
+  let (board, diagnostics) = vbml.parse_with_diagnostics().expect("failed to parse vbml");
+  assert!(board.iter().flatten().all(|code| *code == 0));
+  assert!(diagnostics
+    .iter()
+    .any(|diagnostic| matches!(diagnostic.kind, VbmlDiagnosticKind::ClippedCells { .. })));
+  assert!(vbml.parse_dyn().is_ok());
+  assert!(matches!(
+    vbml.parser().strict().parse(),
+    Err(VbmlError::OutOfBounds { component: 0, .. })
+  ));
+}
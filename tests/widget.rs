@@ -0,0 +1,106 @@
+use vestaboard::board::{
+  Bar, BarChart, BoardData, BoardError, CharacterCode, Orientation, ProgressBar, Rect, Sparkline, Thresholds,
+};
+
+#[test]
+fn it_colors_by_thresholds() {
+  let higher_is_worse = Thresholds::new(50.0, 80.0);
+  assert_eq!(higher_is_worse.color(10.0), CharacterCode::Green);
+  assert_eq!(higher_is_worse.color(50.0), CharacterCode::Yellow);
+  assert_eq!(higher_is_worse.color(95.0), CharacterCode::Red);
+
+  let lower_is_worse = Thresholds::new(0.5, 0.25);
+  assert_eq!(lower_is_worse.color(0.9), CharacterCode::Green);
+  assert_eq!(lower_is_worse.color(0.4), CharacterCode::Yellow);
+  assert_eq!(lower_is_worse.color(0.1), CharacterCode::Red);
+}
+
+#[test]
+fn it_draws_progress_bars() {
+  let mut board = BoardData::<1, 22>::default();
+  let bar = ProgressBar::new(3.0, 4.0).label("build");
+
+  board.draw_progress_bar(Rect::new(0, 0, 1, 22), &bar).unwrap();
+  assert_eq!(board.to_grid(), format!("BUILD {}    75%\n", "{66}".repeat(9)));
+}
+
+#[test]
+fn it_fits_progress_bars_in_small_regions() {
+  let mut board = BoardData::<1, 6>::default();
+  let bar = ProgressBar::new(1.0, 1.0)
+    .label("a very long label")
+    .color(Thresholds::new(0.5, 0.9));
+
+  board.draw_progress_bar(Rect::new(0, 0, 1, 6), &bar).unwrap();
+  assert_eq!(board.to_grid(), "{63} 100%\n");
+}
+
+#[test]
+fn it_draws_thick_progress_bars() {
+  let mut board = BoardData::<3, 5>::default();
+  let bar = ProgressBar::new(0.5, 1.0)
+    .label("ab")
+    .show_value(false)
+    .empty(CharacterCode::White);
+
+  board.draw_progress_bar(Rect::new(0, 0, 3, 5), &bar).unwrap();
+  assert_eq!(board.to_grid(), "   {66}{69}\nAB {66}{69}\n   {66}{69}\n");
+}
+
+#[test]
+fn it_draws_newline_tiles_as_blanks() {
+  let mut board = BoardData::<1, 4>::default();
+  let bar = ProgressBar::new(0.5, 1.0)
+    .show_value(false)
+    .color(CharacterCode::Newline)
+    .empty(CharacterCode::Newline);
+
+  board.draw_progress_bar(Rect::new(0, 0, 1, 4), &bar).unwrap();
+  assert_eq!(board, [[0; 4]]);
+  assert!(board.invalid_cells().is_empty());
+}
+
+#[test]
+fn it_draws_horizontal_bar_charts() {
+  let mut board = BoardData::<3, 12>::default();
+  let chart = BarChart::new(vec![Bar::new("cpu", 50.0), Bar::new("io", 100.0)]).color(Thresholds::new(75.0, 90.0));
+
+  board.draw_bar_chart(Rect::new(0, 0, 3, 12), &chart).unwrap();
+  assert_eq!(
+    board.to_grid(),
+    format!("CPU {}    50\nIO  {} 100\n\n", "{66}".repeat(2), "{63}".repeat(4))
+  );
+
+  let too_many = BarChart::new(vec![Bar::new("a", 1.0); 4]);
+  assert!(matches!(
+    board.draw_bar_chart(Rect::new(0, 0, 3, 12), &too_many),
+    Err(BoardError::OutOfBounds { .. })
+  ));
+}
+
+#[test]
+fn it_draws_vertical_bar_charts() {
+  let mut board = BoardData::<4, 5>::default();
+  let chart = BarChart::new(vec![Bar::new("mon", 1.0), Bar::new("tue", 3.0), Bar::new("wed", 2.0)])
+    .orientation(Orientation::Vertical)
+    .color(CharacterCode::Blue);
+
+  board.draw_bar_chart(Rect::new(0, 0, 4, 5), &chart).unwrap();
+  assert_eq!(board.to_grid(), "  {67}\n  {67} {67}\n{67} {67} {67}\nM T W\n");
+
+  assert!(board.draw_bar_chart(Rect::new(0, 0, 4, 4), &chart).is_err());
+}
+
+#[test]
+fn it_draws_sparklines() {
+  let mut board = BoardData::<3, 4>::default();
+  let sparkline = Sparkline::new(vec![100.0, 0.0, 1.0, 2.0, 4.0]).color(CharacterCode::Violet);
+
+  board.draw_sparkline(Rect::new(0, 0, 3, 4), &sparkline).unwrap();
+  assert_eq!(board.to_grid(), "   {68}\n {68}{68}{68}\n{68}{68}{68}{68}\n");
+
+  let mut board = BoardData::<1, 4>::default();
+  let sparkline = Sparkline::new(vec![1.0, 5.0]).color(Thresholds::new(3.0, 10.0));
+  board.draw_sparkline(Rect::new(0, 0, 1, 4), &sparkline).unwrap();
+  assert_eq!(board.to_grid(), "  {66}{65}\n");
+}